@@ -4,7 +4,7 @@ type HiSparseBitset = hi_sparse_bitset::SmallBitSet<hi_sparse_bitset::config::_1
 
 fn hi_sparse_bitset_insert(in_block: usize) -> HiSparseBitset{
     let mut set: HiSparseBitset = Default::default();
-    for lvl0 in 0..128 {
+    for lvl0 in 0..127 {
         for lvl1 in 0..6 {
             let offset = lvl0*128*128 + lvl1*128;
             for i in 0..in_block{
@@ -15,8 +15,20 @@ fn hi_sparse_bitset_insert(in_block: usize) -> HiSparseBitset{
     set    
 }
 
+fn hi_sparse_bitset_union_with_sorted_iter(in_block: usize) -> HiSparseBitset{
+    let mut set: HiSparseBitset = Default::default();
+    set.union_with_sorted_iter(
+        (0..127).flat_map(|lvl0| (0..6).flat_map(move |lvl1| {
+            let offset = lvl0*128*128 + lvl1*128;
+            offset..offset + in_block
+        }))
+    );
+    set
+}
+
 pub fn bench_iter(c: &mut Criterion) {
     c.bench_function("hi_sparse_bitset insert", |b| b.iter(|| hi_sparse_bitset_insert(black_box(80))));
+    c.bench_function("hi_sparse_bitset union_with_sorted_iter", |b| b.iter(|| hi_sparse_bitset_union_with_sorted_iter(black_box(80))));
 }
 
 criterion_group!(benches_iter, bench_iter);
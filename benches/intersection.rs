@@ -5,7 +5,8 @@ mod common;
 use std::ops::ControlFlow;
 use std::collections::HashSet;
 use criterion::{AxisScale, Criterion, criterion_group, criterion_main, PlotConfiguration};
-use hi_sparse_bitset::{BitSet, BitSetInterface, reduce};
+use hi_sparse_bitset::{BitSet, BitSetInterface, reduce, reduce_w_cache};
+use hi_sparse_bitset::cache::FixedCache;
 use hi_sparse_bitset::ops::And;
 use hi_sparse_bitset::iter::{BlockCursor, IndexCursor, SimpleBlockIter, SimpleIndexIter};
 use ControlFlow::*;
@@ -64,6 +65,18 @@ fn hi_sparse_bitset_reduce_and_caching_iter<Conf: Config>(sets: &[BitSet<Conf>])
     reduce.into_iter().count()
 }
 
+// === Materialize ===
+fn hi_sparse_bitset_reduce_and_fixed_cache_collect<Conf: Config>(sets: &[BitSet<Conf>]) -> BitSet<Conf> {
+    let reduce = reduce_w_cache(And, sets.iter(), FixedCache::<32>).unwrap();
+    let mut set = BitSet::default();
+    set.extend(reduce.into_block_iter());
+    set
+}
+
+fn hi_sparse_bitset_apply_batch_and<Conf: Config>(sets: &[BitSet<Conf>]) -> BitSet<Conf> {
+    BitSet::apply_batch(And, sets)
+}
+
 
 // ---- OP -----
 // === Block iter ===
@@ -234,6 +247,9 @@ pub fn bench_iter(c: &mut Criterion) {
             // === Iter ===
             bench(group, "hi_sparse_bitset_reduce_and_simple_iter", name, hi_sparse_sets, hi_sparse_bitset_reduce_and_simple_iter);
             bench(group, "hi_sparse_bitset_reduce_and_caching_iter", name, hi_sparse_sets, hi_sparse_bitset_reduce_and_caching_iter);
+            // === Materialize ===
+            bench(group, "hi_sparse_bitset_reduce_and_fixed_cache_collect", name, hi_sparse_sets, hi_sparse_bitset_reduce_and_fixed_cache_collect);
+            bench(group, "hi_sparse_bitset_apply_batch_and", name, hi_sparse_sets, hi_sparse_bitset_apply_batch_and);
 
             // ---- OP ----
             // === Block iter ===
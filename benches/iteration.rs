@@ -19,5 +19,41 @@ pub fn bench_iter(c: &mut Criterion) {
     c.bench_function("hi_sparse_bitset iter", |b| b.iter(|| iteration(black_box(&set))));
 }
 
-criterion_group!(benches_iter, bench_iter);
+pub fn bench_count(c: &mut Criterion) {
+    let mut set: HiSparseBitset = Default::default();
+    for i in 0..30_000{
+        set.insert(i*7);
+    }
+    
+    c.bench_function("hi_sparse_bitset block_iter count", |b| b.iter(|| black_box(&set).block_iter().count()));
+    c.bench_function("hi_sparse_bitset block_iter naive count", |b| b.iter(|| black_box(&set).block_iter().map(|_| 1).sum::<usize>()));
+    c.bench_function("hi_sparse_bitset iter count", |b| b.iter(|| black_box(&set).iter().count()));
+    c.bench_function("hi_sparse_bitset iter naive count", |b| b.iter(|| black_box(&set).iter().map(|_| 1).sum::<usize>()));
+}
+
+pub fn bench_compact(c: &mut Criterion) {
+    use rand::{Rng, SeedableRng};
+    type BigBitSet = hi_sparse_bitset::BitSet<hi_sparse_bitset::config::_256bit>;
+    
+    fn len(set: &BigBitSet) -> usize {
+        set.block_iter().map(|block| block.len()).sum()
+    }
+    
+    // N random inserts, then N/2 removes - blocks are scattered across storage.
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let indices: Vec<usize> = (0..400_000).map(|_| rng.gen_range(0..BigBitSet::max_capacity())).collect();
+    let mut set: BigBitSet = Default::default();
+    for &index in &indices{
+        set.insert(index);
+    }
+    for &index in indices.iter().step_by(2){
+        set.remove(index);
+    }
+    c.bench_function("hi_sparse_bitset iter fragmented", |b| b.iter(|| len(black_box(&set))));
+    
+    set.compact();
+    c.bench_function("hi_sparse_bitset iter compacted", |b| b.iter(|| len(black_box(&set))));
+}
+
+criterion_group!(benches_iter, bench_iter, bench_count, bench_compact);
 criterion_main!(benches_iter);
\ No newline at end of file
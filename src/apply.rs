@@ -1,22 +1,24 @@
-use std::marker::PhantomData;
-use std::mem;
-use std::mem::{ManuallyDrop, MaybeUninit};
-use std::ptr::addr_of_mut;
+use core::marker::PhantomData;
+use core::mem;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr::addr_of_mut;
 use crate::ops::*;
 use crate::BitSetInterface;
 use crate::internals::impl_bitset;
 use crate::bitset_interface::{BitSetBase, LevelMasks, LevelMasksIterExt};
+use crate::bit_block::BitBlock;
 use crate::config::Config;
 
 /// Binary operation application, as lazy bitset.
 ///
 /// Created by [apply], or by applying [BitOr], [BitAnd], [BitXor],
-/// [Sub] operations on [BitSetInterface]s.
+/// [Sub], [Shr] operations on [BitSetInterface]s.
 /// 
-/// [BitOr]: std::ops::BitOr
-/// [BitAnd]: std::ops::BitAnd 
-/// [BitXor]: std::ops::BitXor
-/// [Sub]: std::ops::Sub
+/// [BitOr]: core::ops::BitOr
+/// [BitAnd]: core::ops::BitAnd 
+/// [BitXor]: core::ops::BitXor
+/// [Sub]: core::ops::Sub
+/// [Shr]: core::ops::Shr
 /// [apply]: crate::apply()
 /// [BitSetInterface]: crate::BitSetInterface
 #[derive(Clone)]
@@ -76,6 +78,11 @@ where
             self.s2.data_mask(level0_index, level1_index)
         )
     }
+    
+    #[inline]
+    fn memory_usage_estimate(&self) -> usize {
+        self.s1.memory_usage_estimate() + self.s2.memory_usage_estimate()
+    }
 }
 
 impl<Op, S1, S2> LevelMasksIterExt for Apply<Op, S1, S2>
@@ -127,7 +134,13 @@ where
         );
 
         let mask = Op::hierarchy_op(mask1, mask2);
-        (mask, v1 | v2)
+        // Some operations raise hierarchy bits of empty operands (like Implication).
+        // P.S. should be const, but act as const anyway.
+        /*const*/ let raises_empty = !Op::hierarchy_op(
+            <Self::Conf as Config>::Level1BitBlock::zero(), 
+            <Self::Conf as Config>::Level1BitBlock::zero()
+        ).is_zero();
+        (mask, v1 | v2 | raises_empty)
     }
 
     #[inline]
@@ -0,0 +1,186 @@
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr::addr_of_mut;
+use crate::ops::*;
+use crate::BitSetInterface;
+use crate::internals::impl_bitset;
+use crate::bitset_interface::{BitSetBase, LevelMasks, LevelMasksIterExt};
+use crate::bit_block::BitBlock;
+use crate::config::Config;
+
+/// Associative operation application between three bitsets, as lazy bitset.
+/// 
+/// Same as `apply(op, apply(op, s1, s2), s3)`, but all three masks 
+/// are computed together, in one layer.
+///
+/// Created by [apply3].
+/// 
+/// [apply3]: crate::apply3()
+#[derive(Clone)]
+pub struct Apply3<Op, S1, S2, S3>{
+    pub(crate) s1: S1,
+    pub(crate) s2: S2,
+    pub(crate) s3: S3,
+    pub(crate) phantom: PhantomData<Op>
+}
+impl<Op, S1, S2, S3> Apply3<Op, S1, S2, S3>{
+    #[inline]
+    pub(crate) fn new(_:Op, s1:S1, s2:S2, s3:S3) -> Self{
+        Apply3 { s1, s2, s3, phantom:PhantomData }
+    }
+}
+
+impl<Op, S1, S2, S3> BitSetBase for Apply3<Op, S1, S2, S3>
+where
+    Op: Associative,
+    S1: LevelMasks,
+    S2: LevelMasks<Conf = S1::Conf>,
+    S3: LevelMasks<Conf = S1::Conf>,
+{
+    type Conf = S1::Conf;
+    
+    /// true if S1, S2, S3 and Op are `TrustedHierarchy`. 
+    const TRUSTED_HIERARCHY: bool = 
+        Op::TRUSTED_HIERARCHY 
+        & S1::TRUSTED_HIERARCHY & S2::TRUSTED_HIERARCHY & S3::TRUSTED_HIERARCHY;
+}
+
+impl<Op, S1, S2, S3> LevelMasks for Apply3<Op, S1, S2, S3>
+where
+    Op: Associative,
+    S1: LevelMasks,
+    S2: LevelMasks<Conf = S1::Conf>,
+    S3: LevelMasks<Conf = S1::Conf>,
+{
+    #[inline]
+    fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
+        Op::hierarchy_op(
+            Op::hierarchy_op(self.s1.level0_mask(), self.s2.level0_mask()),
+            self.s3.level0_mask()
+        )
+    }
+
+    #[inline]
+    unsafe fn level1_mask(&self, level0_index: usize)
+        -> <Self::Conf as Config>::Level1BitBlock
+    {
+        Op::hierarchy_op(
+            Op::hierarchy_op(
+                self.s1.level1_mask(level0_index),
+                self.s2.level1_mask(level0_index)
+            ),
+            self.s3.level1_mask(level0_index)
+        )
+    }
+
+    #[inline]
+    unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
+        -> <Self::Conf as Config>::DataBitBlock
+    {
+        Op::data_op(
+            Op::data_op(
+                self.s1.data_mask(level0_index, level1_index),
+                self.s2.data_mask(level0_index, level1_index)
+            ),
+            self.s3.data_mask(level0_index, level1_index)
+        )
+    }
+    
+    #[inline]
+    fn memory_usage_estimate(&self) -> usize {
+        self.s1.memory_usage_estimate() 
+        + self.s2.memory_usage_estimate() 
+        + self.s3.memory_usage_estimate()
+    }
+}
+
+impl<Op, S1, S2, S3> LevelMasksIterExt for Apply3<Op, S1, S2, S3>
+where
+    Op: Associative,
+    S1: LevelMasksIterExt,
+    S2: LevelMasksIterExt<Conf = S1::Conf>,
+    S3: LevelMasksIterExt<Conf = S1::Conf>,
+{
+    type Level1BlockData = (S1::Level1BlockData, S2::Level1BlockData, S3::Level1BlockData);
+
+    type IterState = (S1::IterState, S2::IterState, S3::IterState);
+
+    #[inline]
+    fn make_iter_state(&self) -> Self::IterState {
+        (self.s1.make_iter_state(), self.s2.make_iter_state(), self.s3.make_iter_state())
+    }
+
+    #[inline]
+    unsafe fn drop_iter_state(&self, state: &mut ManuallyDrop<Self::IterState>) {
+        // ManuallyDrop<T> have the same layout as T.
+        unsafe{
+            self.s1.drop_iter_state(&mut *addr_of_mut!(state.0).cast());
+            self.s2.drop_iter_state(&mut *addr_of_mut!(state.1).cast());
+            self.s3.drop_iter_state(&mut *addr_of_mut!(state.2).cast());
+        }
+    }
+
+    #[inline]
+    unsafe fn init_level1_block_data(
+        &self,
+        state: &mut Self::IterState,
+        level1_block_data: &mut MaybeUninit<Self::Level1BlockData>,
+        level0_index: usize
+    ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
+        // &mut MaybeUninit<(T0, T1, T2)> = (&mut MaybeUninit<T0>, &mut MaybeUninit<T1>, &mut MaybeUninit<T2>) 
+        let (level1_block_data0, level1_block_data1, level1_block_data2) = {
+            let ptr = level1_block_data.as_mut_ptr();
+            let ptr0 = addr_of_mut!((*ptr).0);
+            let ptr1 = addr_of_mut!((*ptr).1);
+            let ptr2 = addr_of_mut!((*ptr).2);
+            (
+                &mut*ptr0.cast::<MaybeUninit<S1::Level1BlockData>>(), 
+                &mut*ptr1.cast::<MaybeUninit<S2::Level1BlockData>>(),
+                &mut*ptr2.cast::<MaybeUninit<S3::Level1BlockData>>()
+            )
+        };
+        
+        let (mask1, v1) = self.s1.init_level1_block_data(
+            &mut state.0, level1_block_data0, level0_index
+        );
+        let (mask2, v2) = self.s2.init_level1_block_data(
+            &mut state.1, level1_block_data1, level0_index
+        );
+        let (mask3, v3) = self.s3.init_level1_block_data(
+            &mut state.2, level1_block_data2, level0_index
+        );
+
+        let mask = Op::hierarchy_op(Op::hierarchy_op(mask1, mask2), mask3);
+        // Same as in Apply.
+        /*const*/ let raises_empty = !Op::hierarchy_op(
+            <Self::Conf as Config>::Level1BitBlock::zero(), 
+            <Self::Conf as Config>::Level1BitBlock::zero()
+        ).is_zero();
+        (mask, v1 | v2 | v3 | raises_empty)
+    }
+
+    #[inline]
+    unsafe fn data_mask_from_block_data(
+        level1_blocks: &Self::Level1BlockData, level1_index: usize
+    ) -> <Self::Conf as Config>::DataBitBlock {
+        let m0 = S1::data_mask_from_block_data(
+            &level1_blocks.0, level1_index
+        );
+        let m1 = S2::data_mask_from_block_data(
+            &level1_blocks.1, level1_index
+        ); 
+        let m2 = S3::data_mask_from_block_data(
+            &level1_blocks.2, level1_index
+        ); 
+        Op::data_op(Op::data_op(m0, m1), m2)
+    }
+}
+
+impl_bitset!(
+    impl<Op, S1, S2, S3> for Apply3<Op, S1, S2, S3> 
+    where 
+        Op: Associative, 
+        S1: BitSetInterface, 
+        S2: BitSetInterface<Conf = S1::Conf>,
+        S3: BitSetInterface<Conf = S1::Conf>
+);
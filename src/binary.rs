@@ -0,0 +1,326 @@
+//! Compact binary format, following bitset hierarchy.
+//! All words are little-endian `u64`s:
+//!
+//! * level0 mask.
+//! * for each raised level0 bit - level1 mask.
+//! * for each raised level1 bit - data block.
+//!
+//! Size is O(allocated blocks). Indices themselves are never written.
+//! 
+//! Stream format (`write_to`/`read_from`) prepends header: 
+//! `MAGIC`, `VERSION` and `SIZE_POT_EXPONENT` of each level bitblock as `u8`s.
+
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+use core::ops::ControlFlow;
+#[cfg(feature = "bincode")]
+use alloc::vec::Vec;
+use crate::{BitBlock, DataBlock, data_block_start_index};
+use crate::bitset_interface::LevelMasks;
+use crate::config::{Config, max_capacity};
+use crate::level::IBlock;
+use crate::raw::RawBitSet;
+
+#[cfg(feature = "std")]
+const MAGIC: [u8; 4] = *b"HSBS";
+#[cfg(feature = "std")]
+const VERSION: u8 = 1;
+
+/// Error returned from `deserialize_bincode()`.
+#[cfg(feature = "bincode")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BinaryDecodeError{
+    /// Input ended in the middle of the hierarchy.
+    UnexpectedEnd,
+    /// Input has bytes after the last data block.
+    TrailingBytes,
+    /// Input has data block out of [max_capacity()] range.
+    /// 
+    /// [max_capacity()]: crate::config::max_capacity
+    OutOfRange,
+}
+
+#[cfg(feature = "bincode")]
+impl core::fmt::Display for BinaryDecodeError{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BinaryDecodeError::UnexpectedEnd => f.write_str("unexpected end of input"),
+            BinaryDecodeError::TrailingBytes => f.write_str("trailing bytes after bitset"),
+            BinaryDecodeError::OutOfRange => f.write_str("data block out of range"),
+        }
+    }
+}
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+impl std::error::Error for BinaryDecodeError{}
+
+#[inline]
+fn write_block<E, B: BitBlock>(write_word: &mut impl FnMut(u64) -> Result<(), E>, block: &B) -> Result<(), E>{
+    for word in block.as_array(){
+        write_word(*word)?;
+    }
+    Ok(())
+}
+
+#[inline]
+fn read_block<E, B: BitBlock>(read_word: &mut impl FnMut() -> Result<u64, E>) -> Result<B, E>{
+    let mut block = B::zero();
+    for word in block.as_array_mut(){
+        *word = read_word()?;
+    }
+    Ok(block)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+fn header<Conf: Config>() -> [u8; 8] {
+    [
+        MAGIC[0], MAGIC[1], MAGIC[2], MAGIC[3], 
+        VERSION,
+        Conf::Level0BitBlock::SIZE_POT_EXPONENT as u8,
+        Conf::Level1BitBlock::SIZE_POT_EXPONENT as u8,
+        Conf::DataBitBlock::SIZE_POT_EXPONENT as u8,
+    ]
+}
+
+impl<Conf, Level0Block, Level1Block, LevelDataBlock>
+    RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock<Mask = Conf::Level0BitBlock>,
+    Level1Block: IBlock<Mask = Conf::Level1BitBlock>,
+    LevelDataBlock: IBlock<Mask = Conf::DataBitBlock>
+{
+    /// Writes hierarchy word by word.
+    fn write_hierarchy<E>(&self, mut write_word: impl FnMut(u64) -> Result<(), E>) -> Result<(), E> {
+        let level0_mask = self.level0_mask();
+        write_block(&mut write_word, &level0_mask)?;
+
+        let mut result = Ok(());
+        let _ = level0_mask.traverse_bits(|level0_index|{
+            let level1_mask = unsafe{ self.level1_mask(level0_index) };
+            if let Err(e) = write_block(&mut write_word, &level1_mask){
+                result = Err(e);
+                return ControlFlow::Break(());
+            }
+            level1_mask.traverse_bits(|level1_index|{
+                let data_mask = unsafe{ self.data_mask(level0_index, level1_index) };
+                if let Err(e) = write_block(&mut write_word, &data_mask){
+                    result = Err(e);
+                    return ControlFlow::Break(());
+                }
+                ControlFlow::Continue(())
+            })
+        });
+        result
+    }
+
+    /// Reads hierarchy word by word.
+    /// 
+    /// Data blocks out of range are rejected with `out_of_range()` error.
+    fn read_hierarchy<E>(
+        mut read_word: impl FnMut() -> Result<u64, E>,
+        out_of_range: impl Fn() -> E
+    ) -> Result<Self, E> {
+        let mut this = Self::default();
+        let level0_mask: Conf::Level0BitBlock = read_block(&mut read_word)?;
+
+        let mut result = Ok(());
+        let _ = level0_mask.traverse_bits(|level0_index|{
+            let level1_mask: Conf::Level1BitBlock = match read_block(&mut read_word){
+                Ok(mask) => mask,
+                Err(e) => { result = Err(e); return ControlFlow::Break(()); }
+            };
+            level1_mask.traverse_bits(|level1_index|{
+                let bit_block = match read_block(&mut read_word){
+                    Ok(mask) => mask,
+                    Err(e) => { result = Err(e); return ControlFlow::Break(()); }
+                };
+                let start_index = data_block_start_index::<Conf>(level0_index, level1_index);
+                if start_index >= max_capacity::<Conf>(){
+                    result = Err(out_of_range());
+                    return ControlFlow::Break(());
+                }
+                this.insert_datablock(DataBlock{ start_index, bit_block });
+                ControlFlow::Continue(())
+            })
+        });
+        result.map(|_| this)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        // Words are batched - to not write each one separately to unbuffered writer.
+        let mut buffer = [0u8; 512];
+        buffer[..8].copy_from_slice(&header::<Conf>());
+        let mut len = 8;
+        self.write_hierarchy(|word|{
+            if len == buffer.len() {
+                writer.write_all(&buffer)?;
+                len = 0;
+            }
+            buffer[len..len + 8].copy_from_slice(&word.to_le_bytes());
+            len += 8;
+            Ok::<(), io::Error>(())
+        })?;
+        writer.write_all(&buffer[..len])
+    }
+
+    #[cfg(feature = "std")]
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut header_bytes = [0; 8];
+        reader.read_exact(&mut header_bytes)?;
+        if header_bytes[..4] != MAGIC {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "not a bitset stream"));
+        }
+        if header_bytes[4] != VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "unsupported bitset stream version"));
+        }
+        if header_bytes != header::<Conf>() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "bitset stream Config mismatch"));
+        }
+        Self::read_hierarchy(
+            ||{
+                let mut bytes = [0; 8];
+                reader.read_exact(&mut bytes)?;
+                Ok(u64::from_le_bytes(bytes))
+            },
+            || io::Error::new(io::ErrorKind::InvalidData, "bitset stream index out of range")
+        )
+    }
+
+    #[cfg(feature = "bincode")]
+    pub fn serialize_bincode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let _ = self.write_hierarchy(|word|{
+            bytes.extend_from_slice(&word.to_le_bytes());
+            Ok::<(), core::convert::Infallible>(())
+        });
+        bytes
+    }
+
+    #[cfg(feature = "bincode")]
+    pub fn deserialize_bincode(mut bytes: &[u8]) -> Result<Self, BinaryDecodeError> {
+        let this = Self::read_hierarchy(
+            ||{
+                if bytes.len() < 8 {
+                    return Err(BinaryDecodeError::UnexpectedEnd);
+                }
+                let (word, rest) = bytes.split_at(8);
+                bytes = rest;
+                Ok(u64::from_le_bytes(word.try_into().unwrap()))
+            },
+            || BinaryDecodeError::OutOfRange
+        )?;
+        if !bytes.is_empty(){
+            return Err(BinaryDecodeError::TrailingBytes);
+        }
+        Ok(this)
+    }
+}
+
+#[cfg(test)]
+mod test{
+    use crate::{BitSet, SmallBitSet};
+    use crate::config::_64bit;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stream_test(){
+        use crate::config::_128bit;
+        
+        let set: BitSet<_64bit> = (0..100_000).step_by(7).collect();
+        let mut bytes = Vec::new();
+        set.write_to(&mut bytes).unwrap();
+        
+        let set2 = BitSet::<_64bit>::read_from(&mut bytes.as_slice()).unwrap();
+        assert_eq!(set, set2);
+        
+        // writes are batched
+        struct CountingWriter(Vec<u8>, usize);
+        impl std::io::Write for CountingWriter{
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.1 += 1;
+                self.0.write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+        }
+        let mut writer = CountingWriter(Vec::new(), 0);
+        set.write_to(&mut writer).unwrap();
+        assert_eq!(writer.0, bytes);
+        assert_eq!(writer.1, bytes.len().div_ceil(512));
+        let set3 = SmallBitSet::<_64bit>::read_from(&mut bytes.as_slice()).unwrap();
+        assert!(set3.iter().eq(set.iter()));
+        
+        // config mismatch
+        let err = BitSet::<_128bit>::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        // truncated
+        let err = BitSet::<_64bit>::read_from(&mut &bytes[..bytes.len()-1]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        // not a stream
+        let err = BitSet::<_64bit>::read_from(&mut &[0u8; 16][..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        // valid header, data block out of range
+        let mut bytes = b"HSBS\x01\x06\x06\x06".to_vec();
+        for word in [1u64 << 63, 1, 1] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        let err = BitSet::<_64bit>::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let err = SmallBitSet::<_64bit>::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_test(){
+        let set: BitSet<_64bit> = [1, 3, 100, 5000, 100_000].into();
+        let bytes = set.serialize_bincode();
+        assert_eq!(BitSet::deserialize_bincode(&bytes), Ok(set.clone()));
+
+        let small_set = SmallBitSet::<_64bit>::deserialize_bincode(&bytes).unwrap();
+        assert!(small_set.iter().eq(set.iter()));
+
+        // 1 level0 mask, 3 level1 masks, 4 data blocks
+        assert_eq!(bytes.len(), (1 + 3 + 4) * 8);
+
+        let empty: BitSet<_64bit> = Default::default();
+        let bytes = empty.serialize_bincode();
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(BitSet::deserialize_bincode(&bytes), Ok(empty));
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_invalid_test(){
+        use crate::BinaryDecodeError;
+        
+        let set: BitSet<_64bit> = [1, 3, 100].into();
+        let mut bytes = set.serialize_bincode();
+        assert_eq!(
+            BitSet::<_64bit>::deserialize_bincode(&bytes[..bytes.len()-1]),
+            Err(BinaryDecodeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            BitSet::<_64bit>::deserialize_bincode(&[]),
+            Err(BinaryDecodeError::UnexpectedEnd)
+        );
+        bytes.push(0);
+        assert_eq!(
+            BitSet::<_64bit>::deserialize_bincode(&bytes),
+            Err(BinaryDecodeError::TrailingBytes)
+        );
+        
+        // last level0 bit of _64bit is out of max_capacity() range
+        let bytes: Vec<u8> = [1u64 << 63, 1, 1].iter().flat_map(|w| w.to_le_bytes()).collect();
+        assert_eq!(
+            BitSet::<_64bit>::deserialize_bincode(&bytes),
+            Err(BinaryDecodeError::OutOfRange)
+        );
+        assert_eq!(
+            SmallBitSet::<_64bit>::deserialize_bincode(&bytes).unwrap_err(),
+            BinaryDecodeError::OutOfRange
+        );
+    }
+}
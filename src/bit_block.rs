@@ -1,5 +1,5 @@
-use std::mem;
-use std::ops::{BitAnd, BitOr, BitXor, ControlFlow};
+use core::mem;
+use core::ops::{BitAnd, BitOr, BitXor, ControlFlow};
 use crate::bit_utils;
 use crate::bit_queue::{ArrayBitQueue, BitQueue, PrimitiveBitQueue};
 
@@ -26,6 +26,16 @@ pub trait BitBlock
 
     fn zero() -> Self;
     
+    /// All bits set.
+    #[inline]
+    fn ones() -> Self {
+        let mut block = Self::zero();
+        for i in block.as_array_mut(){
+            *i = u64::MAX;
+        }
+        block
+    }
+    
     #[inline]
     fn is_zero(&self) -> bool {
         self == &Self::zero()
@@ -62,6 +72,17 @@ pub trait BitBlock
         bit_utils::traverse_array_one_bits(self.as_array(), f)
     }
 
+    /// Same as [traverse_bits], but from the highest bit to the lowest.
+    /// 
+    /// [traverse_bits]: Self::traverse_bits
+    #[inline]
+    fn traverse_bits_rev<F>(&self, f: F) -> ControlFlow<()>
+    where
+        F: FnMut(usize) -> ControlFlow<()>
+    {
+        bit_utils::traverse_array_one_bits_rev(self.as_array(), f)
+    }
+
     type BitsIter: BitQueue;
     fn into_bits_iter(self) -> Self::BitsIter;
     
@@ -191,3 +212,75 @@ impl BitBlock for wide::u64x4{
         self.as_array_mut()
     }
 }
+
+/// 512 bit block. Pair of [wide::u64x4].
+/// 
+/// [wide] does not have 512 bit SIMD type - operations are done on 
+/// two 256 bit halves. 
+#[cfg(feature = "simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(C, align(64))]
+pub struct U64x8([wide::u64x4; 2]);
+
+#[cfg(feature = "simd")]
+impl U64x8{
+    #[inline]
+    pub fn to_array(self) -> [u64; 8] {
+        unsafe{ mem::transmute(self) }
+    }
+}
+
+#[cfg(feature = "simd")]
+macro_rules! impl_u64x8_op {
+    ($op_trait:ident, $op_fn:ident) => {
+        impl $op_trait for U64x8{
+            type Output = Self;
+            
+            #[inline]
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                Self([
+                    $op_trait::$op_fn(self.0[0], rhs.0[0]), 
+                    $op_trait::$op_fn(self.0[1], rhs.0[1])
+                ])
+            }
+        }
+    };
+}
+#[cfg(feature = "simd")]
+impl_u64x8_op!(BitAnd, bitand);
+#[cfg(feature = "simd")]
+impl_u64x8_op!(BitOr, bitor);
+#[cfg(feature = "simd")]
+impl_u64x8_op!(BitXor, bitxor);
+
+#[cfg(feature = "simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
+impl BitBlock for U64x8{
+    const SIZE_POT_EXPONENT: usize = 9;
+
+    #[inline]
+    fn zero() -> Self {
+        Self([wide::u64x4::ZERO; 2])
+    }
+
+    type BitsIter = ArrayBitQueue<u64, 8>;
+    #[inline]
+    fn into_bits_iter(self) -> Self::BitsIter {
+        Self::BitsIter::new(self.to_array())
+    }
+
+    #[inline]
+    fn as_array(&self) -> &[u64] {
+        unsafe {
+            mem::transmute::<&U64x8, &[u64; 8]>(self)
+        }
+    }
+
+    #[inline]
+    fn as_array_mut(&mut self) -> &mut [u64] {
+        unsafe {
+            mem::transmute::<&mut U64x8, &mut [u64; 8]>(self)
+        }
+    }
+}
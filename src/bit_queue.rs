@@ -1,6 +1,7 @@
-use std::mem;
-use std::mem::{ManuallyDrop, size_of};
-use std::ops::ControlFlow;
+use core::iter::FusedIterator;
+use core::mem;
+use core::mem::{ManuallyDrop, size_of};
+use core::ops::ControlFlow;
 
 use crate::bit_utils::{one_bits_iter, OneBitsIter, self};
 use crate::Primitive;
@@ -137,8 +138,28 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.bit_block_iter.next()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bit_block_iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.bit_block_iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.bit_block_iter.nth(n)
+    }
 }
 
+impl<P> FusedIterator for PrimitiveBitQueue<P>
+where
+    P: Primitive
+{}
+
 /// [BitQueue] for array of [Primitive]s.
 #[derive(Clone)]
 pub struct ArrayBitQueue<P, const N: usize>{
@@ -271,7 +292,7 @@ where
             // compiler should optimize away this for newly constructed BitQueue.
             *self.bit_block_iters.get_unchecked_mut(self.bit_block_index) = self.bit_block_iters[0];
             
-            let slice: &[P] = std::slice::from_raw_parts(
+            let slice: &[P] = core::slice::from_raw_parts(
                 // cast is safe because OneBitsIter<P> transmutable to P.
                 self.bit_block_iters.as_ptr().add(self.bit_block_index).cast(),
                 N - self.bit_block_index
@@ -313,6 +334,41 @@ where
         }
     }
 
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // active block + not yet activated ones.
+        let len = self.bit_block_iters[self.bit_block_index+1..].iter()
+            .fold(self.bit_block_iters[0].count(), |sum, iter| sum + iter.count());
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        // skip whole elements
+        loop {
+            let len = self.bit_block_iters[0].count();
+            if n < len {
+                break;
+            }
+            n -= len;
+            if self.bit_block_index == N-1 {
+                self.bit_block_iters[0] = one_bits_iter(P::ZERO);
+                return None;
+            }
+            self.bit_block_index += 1;
+            self.bit_block_iters[0] = unsafe {
+                *self.bit_block_iters.get_unchecked_mut(self.bit_block_index)
+            };
+        }
+        self.bit_block_iters[0].nth(n)
+            .map(|index| self.bit_block_index * size_of::<P>() * 8 + index)
+    }
+
     #[inline]
     fn for_each<F>(self, mut f: F)
     where
@@ -323,4 +379,9 @@ where
             ControlFlow::Continue(())
         });
     }
-}
\ No newline at end of file
+}
+
+impl<P, const N: usize> FusedIterator for ArrayBitQueue<P, N>
+where
+    P: Primitive
+{}
\ No newline at end of file
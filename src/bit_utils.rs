@@ -1,5 +1,6 @@
-use std::mem::size_of;
-use std::ops::ControlFlow;
+use core::iter::FusedIterator;
+use core::mem::size_of;
+use core::ops::ControlFlow;
 use crate::Primitive;
 
 /// Block ordering undefined. But same as [get_array_bit].
@@ -126,6 +127,50 @@ where
     ControlFlow::Continue(())
 }
 
+/// Same as [traverse_array_one_bits], but in reverse order - from the highest bit.
+#[inline]
+pub fn traverse_array_one_bits_rev<P, F>(array: &[P], mut f: F) -> ControlFlow<()>
+where
+    P: Primitive,
+    F: FnMut(usize) -> ControlFlow<()>
+{
+    for i in (0..array.len()).rev(){
+        let element = unsafe{*array.get_unchecked(i)};
+        let control = traverse_one_bits_rev(
+            element,
+            |r|{
+                let index = i*size_of::<P>()*8 + r;
+                f(index)
+            }
+        );
+        if control.is_break(){
+            return ControlFlow::Break(());
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// Same as [traverse_one_bits], but in reverse order - from the highest bit.
+#[inline]
+pub fn traverse_one_bits_rev<P, F>(mut element: P, mut f: F) -> ControlFlow<()>
+where
+    P: Primitive,
+    F: FnMut(usize) -> ControlFlow<()>
+{
+    let last_bit = size_of::<P>()*8 - 1;
+    while !element.is_zero() {
+        let index = last_bit - element.leading_zeros() as usize;
+
+        let control = f(index);
+        if control.is_break(){
+            return ControlFlow::Break(());
+        }
+
+        element ^= P::ONE << index;
+    }
+    ControlFlow::Continue(())
+}
+
 /// This is 15% slower then "traverse" version
 #[inline]
 pub fn one_bits_iter<P>(element: P) -> OneBitsIter<P> {
@@ -163,5 +208,34 @@ where
             None
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.element.count_ones() as usize;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.element.count_ones() as usize
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.element.count_ones() as usize {
+            self.element = P::ZERO;
+            return None;
+        }
+        for _ in 0..n {
+            // reset lowest one bit
+            let t: P = self.element & self.element.wrapping_neg();
+            self.element ^= t;
+        }
+        self.next()
+    }
 }
 
+impl<P> FusedIterator for OneBitsIter<P>
+where
+    P: Primitive,
+{}
@@ -46,4 +46,4 @@ impl<Conf: Config> BitSetBase for BitSet<Conf> {
 }
 derive_raw!(
     impl<Conf> BitSet<Conf> as RawBitSet<Conf> where Conf: Config  
-);
\ No newline at end of file
+);
@@ -1,8 +1,22 @@
-use std::mem::{ManuallyDrop, MaybeUninit};
-use std::ops::ControlFlow;
-use crate::{assume, level_indices};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{ControlFlow, RangeBounds, RangeInclusive};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use crate::{assume, data_block_start_index, level_indices};
+use crate::apply::Apply;
 use crate::bit_block::BitBlock;
-use crate::config::{DefaultBlockIterator, Config, DefaultIndexIterator};
+use crate::config::{DefaultBlockIterator, Config, DefaultIndexIterator, max_addressable_index, max_capacity};
+use crate::iter::{BlockCursor, BoundedBlockIter, BoundedIndexIter, ChunksIter, ComplementBoundedIter, ExactIndexIter, IndexCursor, RangesIter, RevBlockIter, RevIndexIter};
+#[cfg(feature = "rayon")]
+use crate::iter::{ParBlockIter, ParIndexIter};
+use crate::ops::{And, BitSetOp, Or, Sub, Xor};
+use crate::complement::ComplementBitSet;
+use crate::range::{ones_in_range, RangeBitSet};
 
 // We have this separate trait with Config, to avoid making LevelMasks public.
 pub trait BitSetBase {
@@ -34,6 +48,15 @@ pub trait LevelMasks: BitSetBase{
     /// indices are not checked
     unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
         -> <Self::Conf as Config>::DataBitBlock;
+    
+    /// Memory used by bitset, including bitsets it refers to, in bytes.
+    /// 
+    /// Used by [BitSetInterface::approximate_memory_usage()]. 
+    /// Lazy bitsets should sum up their operands.
+    #[inline]
+    fn memory_usage_estimate(&self) -> usize {
+        core::mem::size_of_val(self)
+    }
 }
 
 /// More sophisticated masks interface, optimized for iteration speed of 
@@ -141,66 +164,81 @@ pub trait LevelMasksIterExt: LevelMasks{
     ) -> <Self::Conf as Config>::DataBitBlock;
 }
 
-impl<'a, T: LevelMasks> BitSetBase for &'a T {
-    type Conf = T::Conf;
-    const TRUSTED_HIERARCHY: bool = T::TRUSTED_HIERARCHY;
-}
-impl<'a, T: LevelMasks> LevelMasks for &'a T {
-    #[inline]
-    fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
-        <T as LevelMasks>::level0_mask(self)
-    }
-
-    #[inline]
-    unsafe fn level1_mask(&self, level0_index: usize)
-        -> <Self::Conf as Config>::Level1BitBlock
-    {
-        <T as LevelMasks>::level1_mask(self, level0_index)
-    }
-
-    #[inline]
-    unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
-        -> <Self::Conf as Config>::DataBitBlock
-    {
-        <T as LevelMasks>::data_mask(self, level0_index, level1_index)
-    }
-}
-impl<'a, T: LevelMasksIterExt> LevelMasksIterExt for &'a T {
-    type Level1BlockData = T::Level1BlockData;
-
-    type IterState = T::IterState;
-
-    #[inline]
-    fn make_iter_state(&self) -> Self::IterState {
-        <T as LevelMasksIterExt>::make_iter_state(self)
-    }
-
-    #[inline]
-    unsafe fn drop_iter_state(&self, cache: &mut ManuallyDrop<Self::IterState>) {
-        <T as LevelMasksIterExt>::drop_iter_state(self, cache)
-    }
-
-    #[inline]
-    unsafe fn init_level1_block_data(
-        &self,
-        state: &mut Self::IterState,
-        level1_blocks: &mut MaybeUninit<Self::Level1BlockData>,
-        level0_index: usize
-    ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
-        <T as LevelMasksIterExt>::init_level1_block_data(
-            self, state, level1_blocks, level0_index
-        )
-    }
-
-    #[inline]
-    unsafe fn data_mask_from_block_data(
-        level1_blocks: &Self::Level1BlockData, level1_index: usize
-    ) -> <Self::Conf as Config>::DataBitBlock {
-        <T as LevelMasksIterExt>::data_mask_from_block_data(
-            level1_blocks, level1_index
-        )
-    }
+/// Forwards [LevelMasks] and [LevelMasksIterExt] of pointer to pointee.
+macro_rules! impl_level_masks_for_ptr {
+    (impl<$($lt:lifetime,)? T> for $ptr:ty) => {
+        impl<$($lt,)? T: LevelMasks> BitSetBase for $ptr {
+            type Conf = T::Conf;
+            const TRUSTED_HIERARCHY: bool = T::TRUSTED_HIERARCHY;
+        }
+        impl<$($lt,)? T: LevelMasks> LevelMasks for $ptr {
+            #[inline]
+            fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
+                <T as LevelMasks>::level0_mask(self)
+            }
+        
+            #[inline]
+            unsafe fn level1_mask(&self, level0_index: usize)
+                -> <Self::Conf as Config>::Level1BitBlock
+            {
+                <T as LevelMasks>::level1_mask(self, level0_index)
+            }
+        
+            #[inline]
+            unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
+                -> <Self::Conf as Config>::DataBitBlock
+            {
+                <T as LevelMasks>::data_mask(self, level0_index, level1_index)
+            }
+            
+            #[inline]
+            fn memory_usage_estimate(&self) -> usize {
+                <T as LevelMasks>::memory_usage_estimate(self)
+            }
+        }
+        impl<$($lt,)? T: LevelMasksIterExt> LevelMasksIterExt for $ptr {
+            type Level1BlockData = T::Level1BlockData;
+        
+            type IterState = T::IterState;
+        
+            #[inline]
+            fn make_iter_state(&self) -> Self::IterState {
+                <T as LevelMasksIterExt>::make_iter_state(self)
+            }
+        
+            #[inline]
+            unsafe fn drop_iter_state(&self, cache: &mut ManuallyDrop<Self::IterState>) {
+                <T as LevelMasksIterExt>::drop_iter_state(self, cache)
+            }
+        
+            #[inline]
+            unsafe fn init_level1_block_data(
+                &self,
+                state: &mut Self::IterState,
+                level1_blocks: &mut MaybeUninit<Self::Level1BlockData>,
+                level0_index: usize
+            ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
+                <T as LevelMasksIterExt>::init_level1_block_data(
+                    self, state, level1_blocks, level0_index
+                )
+            }
+        
+            #[inline]
+            unsafe fn data_mask_from_block_data(
+                level1_blocks: &Self::Level1BlockData, level1_index: usize
+            ) -> <Self::Conf as Config>::DataBitBlock {
+                <T as LevelMasksIterExt>::data_mask_from_block_data(
+                    level1_blocks, level1_index
+                )
+            }
+        }
+    };
 }
+impl_level_masks_for_ptr!(impl<'a, T> for &'a T);
+impl_level_masks_for_ptr!(impl<T> for Box<T>);
+impl_level_masks_for_ptr!(impl<T> for Rc<T>);
+#[cfg(target_has_atomic = "ptr")]
+impl_level_masks_for_ptr!(impl<T> for Arc<T>);
 
 // User-side interface
 /// Bitset interface.
@@ -252,16 +290,180 @@ pub unsafe trait BitSetInterface
         DefaultIndexIterator::new(self)
     }
     
+    /// Blocks, starting from the block containing `first`.
+    /// 
+    /// Same as `block_iter().move_to(BlockCursor::from(first))`.
+    #[inline]
+    fn block_iter_from(&self, first: usize) -> DefaultBlockIterator<&'_ Self> {
+        DefaultBlockIterator::new(self).move_to(BlockCursor::from(first))
+    }
+    
+    /// Blocks, up to and including the block containing `last`.
+    #[inline]
+    fn block_iter_until(&self, last: usize) -> BoundedBlockIter<&'_ Self> {
+        BoundedBlockIter::new(DefaultBlockIterator::new(self), last)
+    }
+    
+    /// Indices `>= first`.
+    /// 
+    /// Same as `iter().move_to(IndexCursor::from(first))`.
+    #[inline]
+    fn iter_from(&self, first: usize) -> DefaultIndexIterator<&'_ Self> {
+        DefaultIndexIterator::new(self).move_to(IndexCursor::from(first))
+    }
+    
+    /// Indices `<= last`.
+    #[inline]
+    fn iter_until(&self, last: usize) -> BoundedIndexIter<&'_ Self> {
+        BoundedIndexIter::new(DefaultIndexIterator::new(self), last)
+    }
+    
+    /// Indices within `range`.
+    /// 
+    /// Starts from `range` start, as [iter_from()], and stops after `range` end,
+    /// as [iter_until()].
+    /// 
+    /// [iter_from()]: Self::iter_from
+    /// [iter_until()]: Self::iter_until
+    #[inline]
+    fn iter_range<R: RangeBounds<usize>>(&self, range: R) -> BoundedIndexIter<&'_ Self> {
+        BoundedIndexIter::with_range(DefaultIndexIterator::new(self), range)
+    }
+    
+    /// Block iterator in reverse order - from the last block to the first.
+    #[inline]
+    fn rev_block_iter(&self) -> RevBlockIter<&'_ Self> {
+        RevBlockIter::new(self)
+    }
+    
+    /// Index iterator in descending order.
+    #[inline]
+    fn rev_iter(&self) -> RevIndexIter<&'_ Self> {
+        RevIndexIter::new(self)
+    }
+    
+    /// Iterate maximal contiguous ranges of indices.
+    #[inline]
+    fn iter_ranges(&self) -> RangesIter<&'_ Self> {
+        RangesIter::new(self)
+    }
+    
+    /// Indices in `0..max`, which are NOT in bitset, in ascending order.
+    /// 
+    /// Iterates [ComplementBitSet] of `self`, limited by [RangeBitSet].
+    /// Data blocks absent in `self` are yielded whole, without
+    /// per-index lookups - O(max / data block size + result size).
+    /// 
+    /// [ComplementBitSet]: crate::ComplementBitSet
+    /// [RangeBitSet]: crate::RangeBitSet
+    #[inline]
+    fn iter_complement_bounded(&self, max: usize) -> ComplementBoundedIter<&'_ Self> {
+        DefaultIndexIterator::new(bitset_complement_bounded(self, max))
+    }
+    
+    /// [iter()] with [ExactSizeIterator].
+    /// 
+    /// Length is computed by [exact_len()] at construction.
+    /// 
+    /// [iter()]: Self::iter
+    /// [exact_len()]: Self::exact_len
+    #[inline]
+    fn exact_iter(&self) -> ExactIndexIter<&'_ Self> {
+        ExactIndexIter::new(self, self.exact_len())
+    }
+    
+    /// Splits bitset into [BitSet]s of (at most) `chunk_size` elements each,
+    /// in ascending order.
+    /// 
+    /// Useful with `par_bridge()` for parallel processing.
+    /// 
+    /// # Panics
+    /// 
+    /// If `chunk_size` is 0.
+    /// 
+    /// [BitSet]: crate::BitSet
+    #[inline]
+    fn chunks(&self, chunk_size: usize) -> ChunksIter<&'_ Self> {
+        ChunksIter::new(self, chunk_size)
+    }
+    
     #[inline]
     fn into_block_iter(self) -> DefaultBlockIterator<Self> {
         DefaultBlockIterator::new(self)
     }
     
+    /// Collect all indices into [Vec], allocated once.
+    /// 
+    /// Capacity is computed by [exact_len()] - popcount pass over data blocks, 
+    /// then data blocks are extended into [Vec] as a whole.
+    /// Result is always sorted.
+    /// 
+    /// [exact_len()]: Self::exact_len
+    #[inline]
+    fn into_sorted_vec(self) -> Vec<usize> {
+        let mut vec = Vec::with_capacity(self.exact_len());
+        DefaultBlockIterator::new(self).for_each(|block| vec.extend(block));
+        vec
+    }
+    
+    /// Parallel [block_iter()].
+    /// 
+    /// [block_iter()]: Self::block_iter
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    fn par_block_iter(&self) -> ParBlockIter<&'_ Self> 
+    where
+        Self: Sync
+    {
+        ParBlockIter::new(self)
+    }
+    
+    /// Parallel [iter()].
+    /// 
+    /// [iter()]: Self::iter
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    fn par_iter(&self) -> ParIndexIter<&'_ Self> 
+    where
+        Self: Sync
+    {
+        ParIndexIter::new(self)
+    }
+    
     #[inline]
     fn contains(&self, index: usize) -> bool {
         bitset_contains(self, index)
     } 
     
+    /// Are all indices of `range` in bitset?
+    /// 
+    /// Checks data blocks covered by range as a whole - O(range / data block size).
+    /// Stops at the first data block, missing any of the range bits.
+    /// Empty range is always contained.
+    #[inline]
+    fn contains_range(&self, range: RangeInclusive<usize>) -> bool {
+        bitset_contains_range(self, range)
+    }
+    
+    /// Number of elements within `range`.
+    /// 
+    /// Iterates only data blocks of range. Inner blocks are counted with
+    /// a single popcount, boundary blocks are masked first.
+    #[inline]
+    fn count_in_range(&self, range: RangeInclusive<usize>) -> usize {
+        bitset_count_in_range(self, range)
+    }
+    
+    /// Number of elements.
+    /// 
+    /// Sums data blocks popcounts - O(data blocks), not O(N).
+    #[inline]
+    fn exact_len(&self) -> usize {
+        bitset_exact_len(self)
+    }
+    
     /// O(1) if [TRUSTED_HIERARCHY], O(N) otherwise.
     /// 
     /// [TRUSTED_HIERARCHY]: BitSetBase::TRUSTED_HIERARCHY
@@ -269,6 +471,209 @@ pub unsafe trait BitSetInterface
     fn is_empty(&self) -> bool {
         bitset_is_empty(self)
     }
+    
+    /// Memory used by bitset, in bytes.
+    /// 
+    /// O(1) for [BitSet] and [SmallBitSet] - counts allocated block capacity,
+    /// without traversal. Unlike exact [BitSet::memory_usage()], heap memory
+    /// of [SmallBitSet] inflated level1 blocks is not counted.
+    /// 
+    /// For lazy bitsets ([Apply], [Reduce]) - memory of all underlying 
+    /// bitsets. Bitsets shared between operands are counted multiple times.
+    /// 
+    /// [BitSet]: crate::BitSet
+    /// [SmallBitSet]: crate::SmallBitSet
+    /// [BitSet::memory_usage()]: crate::BitSet::memory_usage()
+    /// [Reduce]: crate::Reduce
+    #[inline]
+    fn approximate_memory_usage(&self) -> usize {
+        self.memory_usage_estimate()
+    }
+    
+    /// Does `other` contain all elements of `self`?
+    /// 
+    /// Stops at the first element of `self`, which `other` does not contain.
+    #[inline]
+    fn is_subset<Rhs>(&self, other: Rhs) -> bool
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_is_subset(self, other)
+    }
+    
+    /// Does `self` contain all elements of `other`?
+    /// 
+    /// Same as `other.is_subset(self)`.
+    #[inline]
+    fn is_superset<Rhs>(&self, other: Rhs) -> bool
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_is_subset(other, self)
+    }
+    
+    /// Smallest element.
+    /// 
+    /// Descends hierarchy by lowest raised bits, without iterator state.
+    /// O(1) if [TRUSTED_HIERARCHY], otherwise backtracks through empty
+    /// data blocks.
+    /// 
+    /// [TRUSTED_HIERARCHY]: BitSetBase::TRUSTED_HIERARCHY
+    #[inline]
+    fn first(&self) -> Option<usize> {
+        bitset_first(self)
+    }
+    
+    /// Largest element.
+    /// 
+    /// Same as [first()], but descends by highest raised bits.
+    /// 
+    /// [first()]: Self::first
+    #[inline]
+    fn last(&self) -> Option<usize> {
+        bitset_last(self)
+    }
+    
+    /// Number of unset indices before the first element.
+    /// 
+    /// Same as `first().unwrap_or(max_capacity())`. Named after 
+    /// [u64::trailing_zeros()] - bitset is seen as one big bitfield, 
+    /// with index 0 as the least significant bit.
+    #[inline]
+    fn count_trailing_zeros(&self) -> usize {
+        bitset_count_trailing_zeros(self)
+    }
+    
+    /// Number of unset indices after the last element, up to [max_capacity()].
+    /// 
+    /// `max_capacity() - 1 - last()`, or `max_capacity()` for empty bitset.
+    /// Named after [u64::leading_zeros()].
+    /// 
+    /// [max_capacity()]: crate::config::max_capacity
+    #[inline]
+    fn count_leading_zeros(&self) -> usize {
+        bitset_count_leading_zeros(self)
+    }
+    
+    /// Do all elements form a single `first..=last` range, without gaps?
+    /// 
+    /// Empty bitset is contiguous. Traverses data blocks of the first range,
+    /// and stops at the first gap.
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        bitset_is_contiguous(self)
+    }
+    
+    /// `first..=last`, if [is_contiguous()] and not empty.
+    /// 
+    /// [is_contiguous()]: Self::is_contiguous
+    #[inline]
+    fn to_range(&self) -> Option<RangeInclusive<usize>> {
+        bitset_to_range(self)
+    }
+    
+    /// Smallest index in `range`, which is NOT in bitset.
+    /// 
+    /// `range` is clamped to [max_capacity()]. `first_unset(hint..)` finds the 
+    /// first free slot starting from `hint`, `first_unset(..below)` - the 
+    /// first free slot below `below`, same as `iter_complement_bounded(below).next()`.
+    /// 
+    /// Skips full data blocks, without per-index processing. 
+    /// Empty blocks are not visited at all - O(1) for empty set.
+    /// 
+    /// [max_capacity()]: crate::config::max_capacity
+    #[inline]
+    fn first_unset<R: RangeBounds<usize>>(&self, range: R) -> Option<usize> {
+        bitset_first_unset(self, range)
+    }
+    
+    /// Have `self` and `other` no elements in common?
+    /// 
+    /// Traverses only blocks present in both hierarchies, and stops at the 
+    /// first common element.
+    #[inline]
+    fn is_disjoint<Rhs>(&self, other: Rhs) -> bool
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_is_disjoint(self, other)
+    }
+    
+    /// Number of elements in `self & other`.
+    /// 
+    /// Sums popcounts of intersected data blocks - nothing materialized. 
+    #[inline]
+    fn intersection_size<Rhs>(&self, other: Rhs) -> usize
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_apply_size(And, self, other)
+    }
+    
+    /// Number of elements in `self | other`.
+    /// 
+    /// See [intersection_size()].
+    /// 
+    /// [intersection_size()]: Self::intersection_size
+    #[inline]
+    fn union_size<Rhs>(&self, other: Rhs) -> usize
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_apply_size(Or, self, other)
+    }
+    
+    /// Number of elements in `self - other`.
+    /// 
+    /// See [intersection_size()].
+    /// 
+    /// [intersection_size()]: Self::intersection_size
+    #[inline]
+    fn difference_size<Rhs>(&self, other: Rhs) -> usize
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_apply_size(Sub, self, other)
+    }
+    
+    /// Number of elements in `self ^ other`.
+    /// 
+    /// See [intersection_size()].
+    /// 
+    /// [intersection_size()]: Self::intersection_size
+    #[inline]
+    fn symmetric_difference_size<Rhs>(&self, other: Rhs) -> usize
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_apply_size(Xor, self, other)
+    }
+    
+    /// Hamming distance - number of elements in only one of `self`, `other`.
+    /// 
+    /// Same as [symmetric_difference_size()].
+    /// 
+    /// [symmetric_difference_size()]: Self::symmetric_difference_size
+    #[inline]
+    fn hamming_distance<Rhs>(&self, other: Rhs) -> usize
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_apply_size(Xor, self, other)
+    }
+    
+    /// Jaccard similarity `|self & other| / |self | other|`.
+    /// 
+    /// Union size is derived from intersection size: `|a| + |b| - |a & b|`.
+    /// So only shared blocks are intersected, and each bitset blocks 
+    /// are popcounted. Returns 1.0, if both are empty.
+    #[inline]
+    fn jaccard_similarity<Rhs>(&self, other: Rhs) -> f64
+    where
+        Rhs: BitSetInterface<Conf = Self::Conf>
+    {
+        bitset_jaccard_similarity(self, other)
+    }
 }
 
 #[inline]
@@ -281,6 +686,62 @@ pub(crate) fn bitset_contains<S: LevelMasks>(bitset: S, index: usize) -> bool {
     }
 } 
 
+pub(crate) fn bitset_contains_range<S: LevelMasks>(bitset: S, range: RangeInclusive<usize>) -> bool {
+    let (first, last) = (*range.start(), *range.end());
+    if first > last {
+        return true;
+    }
+    if last >= max_addressable_index::<S::Conf>() {
+        return false;
+    }
+    
+    let block_size = <S::Conf as Config>::DataBitBlock::size();
+    let mut block_start = first & !(block_size - 1);
+    while block_start <= last {
+        let (level0_index, level1_index, _) = level_indices::<S::Conf>(block_start);
+        let range_mask: <S::Conf as Config>::DataBitBlock = ones_in_range(
+            first.saturating_sub(block_start),
+            core::cmp::min(last - block_start, block_size - 1)
+        );
+        let data_mask = unsafe{ bitset.data_mask(level0_index, level1_index) };
+        if !((data_mask & range_mask) ^ range_mask).is_zero() {
+            return false;
+        }
+        block_start += block_size;
+    }
+    true
+}
+
+pub(crate) fn bitset_count_in_range<S: LevelMasksIterExt>(bitset: S, range: RangeInclusive<usize>) -> usize {
+    let (first, last) = (*range.start(), *range.end());
+    if first > last {
+        return 0;
+    }
+    
+    use ControlFlow::*;
+    let block_size = <S::Conf as Config>::DataBitBlock::size();
+    let mut count = 0;
+    let _ = DefaultBlockIterator::new(bitset)
+        .move_to(BlockCursor::from(first))
+        .traverse(|block|{
+            if block.start_index > last {
+                return Break(());
+            }
+            let block_last = block.start_index + block_size - 1;
+            if block.start_index >= first && block_last <= last {
+                count += block.count_ones();
+            } else {
+                let range_mask: <S::Conf as Config>::DataBitBlock = ones_in_range(
+                    first.saturating_sub(block.start_index),
+                    core::cmp::min(last - block.start_index, block_size - 1)
+                );
+                count += (block.bit_block & range_mask).count_ones();
+            }
+            Continue(())
+        });
+    count
+}
+
 pub(crate) fn bitset_is_empty<S: LevelMasksIterExt>(bitset: S) -> bool {
     if S::TRUSTED_HIERARCHY{
         return bitset.level0_mask().is_zero();
@@ -296,6 +757,209 @@ pub(crate) fn bitset_is_empty<S: LevelMasksIterExt>(bitset: S) -> bool {
     }).is_continue()
 }
 
+#[inline]
+pub(crate) fn bitset_first<S: LevelMasks>(bitset: S) -> Option<usize> {
+    bitset_find_edge::<S, false>(bitset)
+}
+
+#[inline]
+pub(crate) fn bitset_last<S: LevelMasks>(bitset: S) -> Option<usize> {
+    bitset_find_edge::<S, true>(bitset)
+}
+
+#[inline]
+pub(crate) fn bitset_count_trailing_zeros<S: LevelMasks>(bitset: S) -> usize {
+    bitset_first(bitset).unwrap_or(max_capacity::<S::Conf>())
+}
+
+#[inline]
+pub(crate) fn bitset_count_leading_zeros<S: LevelMasks>(bitset: S) -> usize {
+    let max = max_capacity::<S::Conf>();
+    match bitset_last(bitset) {
+        Some(last) => (max - 1).saturating_sub(last),
+        None => max,
+    }
+}
+
+#[inline]
+pub(crate) fn bitset_is_contiguous<S: LevelMasksIterExt>(bitset: S) -> bool {
+    RangesIter::new(bitset).nth(1).is_none()
+}
+
+#[inline]
+pub(crate) fn bitset_to_range<S: LevelMasksIterExt>(bitset: S) -> Option<RangeInclusive<usize>> {
+    let mut ranges = RangesIter::new(bitset);
+    let range = ranges.next()?;
+    if ranges.next().is_some() {
+        return None;
+    }
+    Some(range)
+}
+
+#[inline]
+pub(crate) fn bitset_first_unset<S, R>(bitset: S, range: R) -> Option<usize>
+where
+    S: LevelMasksIterExt,
+    R: RangeBounds<usize>
+{
+    bitset_first(Apply::new(And, ComplementBitSet::new(bitset), RangeBitSet::<S::Conf>::new(range)))
+}
+
+/// Depth-first search of the first (or last, if `REV`) raised bit.
+/// 
+/// In [TRUSTED_HIERARCHY] the very first visited data block is non-empty.
+/// 
+/// [TRUSTED_HIERARCHY]: BitSetBase::TRUSTED_HIERARCHY
+#[inline]
+fn bitset_find_edge<S: LevelMasks, const REV: bool>(bitset: S) -> Option<usize> {
+    #[inline]
+    fn traverse<B: BitBlock>(block: &B, f: impl FnMut(usize) -> ControlFlow<()>, rev: bool) 
+        -> ControlFlow<()>
+    {
+        if rev {
+            block.traverse_bits_rev(f)
+        } else {
+            block.traverse_bits(f)
+        }
+    }
+    
+    use ControlFlow::*;
+    let mut result = None;
+    let _ = traverse(&bitset.level0_mask(), |level0_index|{
+        let level1_mask = unsafe{ bitset.level1_mask(level0_index) };
+        traverse(&level1_mask, |level1_index|{
+            let data_mask = unsafe{ bitset.data_mask(level0_index, level1_index) };
+            traverse(&data_mask, |data_index|{
+                let start_index = data_block_start_index::<S::Conf>(level0_index, level1_index);
+                result = Some(start_index + data_index);
+                Break(())
+            }, REV)
+        }, REV)
+    }, REV);
+    result
+}
+
+/// Depth-first search of `left` element, not present in `right`.
+/// 
+/// `left - right` hierarchy is `left` hierarchy, so this is done by
+/// emptiness check of lazy difference.
+#[inline]
+pub(crate) fn bitset_is_subset<L, R>(left: L, right: R) -> bool
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>,
+{
+    bitset_is_empty(Apply::new(Sub, left, right))
+}
+
+/// `0..max` part of `!bitset`.
+#[inline]
+pub(crate) fn bitset_complement_bounded<S: LevelMasksIterExt>(bitset: S, max: usize)
+    -> Apply<And, ComplementBitSet<S>, RangeBitSet<S::Conf>>
+{
+    Apply::new(And, ComplementBitSet::new(bitset), RangeBitSet::new(..max))
+}
+
+#[inline]
+pub(crate) fn bitset_exact_len<S: LevelMasksIterExt>(bitset: S) -> usize {
+    let mut count = 0;
+    DefaultBlockIterator::new(bitset)
+        .for_each(|block| count += block.count_ones());
+    count
+}
+
+/// Sum of `op` result data blocks popcounts.
+#[inline]
+pub(crate) fn bitset_apply_size<Op, L, R>(op: Op, left: L, right: R) -> usize
+where
+    Op: BitSetOp,
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>,
+{
+    let mut count = 0;
+    DefaultBlockIterator::new(Apply::new(op, left, right))
+        .for_each(|block| count += block.count_ones());
+    count
+}
+
+#[inline]
+pub(crate) fn bitset_jaccard_similarity<L, R>(left: L, right: R) -> f64
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>,
+{
+    let intersection = bitset_apply_size(And, &left, &right);
+    let union = bitset_exact_len(&left) + bitset_exact_len(&right) - intersection;
+    if union == 0 {
+        return 1.0;
+    }
+    intersection as f64 / union as f64
+}
+
+/// Depth-first search of element, present in both sets.
+/// 
+/// `left & right` hierarchy is intersection of hierarchies, so this is done by
+/// emptiness check of lazy intersection.
+#[inline]
+pub(crate) fn bitset_is_disjoint<L, R>(left: L, right: R) -> bool
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>,
+{
+    bitset_is_empty(Apply::new(And, left, right))
+}
+
+/// `first..=last` or `first`, for single-element range.
+struct RangeEntry(RangeInclusive<usize>);
+impl core::fmt::Debug for RangeEntry{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0.start() == self.0.end() {
+            core::fmt::Debug::fmt(self.0.start(), f)
+        } else {
+            core::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+}
+
+/// `{1..=5, 7, 100..=102}`
+pub(crate) fn bitset_debug_fmt<S>(bitset: S, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+where
+    S: LevelMasksIterExt
+{
+    f.debug_set()
+        .entries(RangesIter::new(bitset).map(RangeEntry))
+        .finish()
+}
+
+/// `1..=5, 7, 100..=102`
+pub(crate) fn bitset_display_fmt<S>(bitset: S, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+where
+    S: LevelMasksIterExt
+{
+    for (i, range) in RangesIter::new(bitset).enumerate(){
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        core::fmt::Debug::fmt(&RangeEntry(range), f)?;
+    }
+    Ok(())
+}
+
+/// Hash of non-empty data blocks, consistent with [bitsets_eq].
+pub(crate) fn bitset_hash<S, H>(bitset: S, state: &mut H)
+where
+    S: LevelMasksIterExt,
+    H: Hasher
+{
+    DefaultBlockIterator::new(bitset).for_each(|block|{
+        if !block.is_empty(){
+            block.start_index.hash(state);
+            block.bit_block.as_array().hash(state);
+        }
+    });
+}
+
 /// Optimistic depth-first check.
 /// 
 /// This traverse-based implementation is faster than using two iterators.
@@ -420,4 +1084,99 @@ where
     }
     
     is_eq
-}
\ No newline at end of file
+}
+/// Lexicographical comparison of sorted index sequences. 
+/// Same as [Ord] of [BTreeSet].
+/// 
+/// Depth-first search for the first differing data block, like in [bitsets_eq].
+/// 
+/// [BTreeSet]: std::collections::BTreeSet
+pub(crate) fn bitsets_cmp<L, R>(left: L, right: R) -> Ordering
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>,
+{
+    let level0_mask = left.level0_mask() | right.level0_mask();
+    
+    let mut left_cache_data  = left.make_iter_state();
+    let mut right_cache_data = right.make_iter_state();
+    
+    let mut left_level1_blocks  = MaybeUninit::new(Default::default());
+    let mut right_level1_blocks = MaybeUninit::new(Default::default());
+    
+    // (first differing index, is it in left)
+    let mut first_diff = None;
+    
+    use ControlFlow::*;
+    let _ = level0_mask.traverse_bits(|level0_index|{
+        let (left_level1_mask, left_valid) = unsafe {
+            left_level1_blocks.assume_init_drop();
+            left.init_level1_block_data(&mut left_cache_data, &mut left_level1_blocks, level0_index)
+        };
+        let (right_level1_mask, right_valid) = unsafe {
+            right_level1_blocks.assume_init_drop();
+            right.init_level1_block_data(&mut right_cache_data, &mut right_level1_blocks, level0_index)
+        };
+        
+        let level1_mask = match (left_valid, right_valid) {
+            (true, true)  => left_level1_mask | right_level1_mask,
+            (true, false) => left_level1_mask,
+            (false, true) => right_level1_mask,
+            (false, false) => return Continue(()),
+        };
+        
+        level1_mask.traverse_bits(|level1_index|{
+            let left_data = if left_valid { unsafe {
+                L::data_mask_from_block_data(left_level1_blocks.assume_init_ref(), level1_index)
+            }} else {
+                BitBlock::zero()
+            };
+            let right_data = if right_valid { unsafe {
+                R::data_mask_from_block_data(right_level1_blocks.assume_init_ref(), level1_index)
+            }} else {
+                BitBlock::zero()
+            };
+            
+            if left_data == right_data {
+                return Continue(());
+            }
+            
+            (left_data ^ right_data).traverse_bits(|data_index|{
+                let index = data_block_start_index::<L::Conf>(level0_index, level1_index) + data_index;
+                first_diff = Some((index, left_data.get_bit(data_index)));
+                Break(())
+            })
+        })
+    });
+    
+    unsafe {
+        left_level1_blocks.assume_init_drop();
+        right_level1_blocks.assume_init_drop();
+    }
+    
+    // Everything before `index` is equal. At `index` - one of the sets have
+    // element, while the other have either greater one, or nothing at all. 
+    match first_diff {
+        None => Ordering::Equal,
+        Some((index, true)) => 
+            if bitset_has_index_after(&right, index) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            },
+        Some((index, false)) => 
+            if bitset_has_index_after(&left, index) {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            },
+    }
+}
+
+#[inline]
+fn bitset_has_index_after<S: LevelMasksIterExt>(bitset: S, index: usize) -> bool {
+    DefaultIndexIterator::new(bitset)
+        .move_to(IndexCursor::from(index + 1))
+        .next()
+        .is_some()
+}
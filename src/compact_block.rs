@@ -1,7 +1,8 @@
-use std::mem::{ManuallyDrop, MaybeUninit};
-use std::ops::{Deref, DerefMut};
-use std::ops::ControlFlow::Continue;
-use std::ptr;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ops::ControlFlow::Continue;
+use core::ptr;
+use alloc::boxed::Box;
 use crate::BitBlock;
 use crate::level::IBlock;
 use crate::primitive::Primitive;
@@ -263,6 +264,15 @@ where
         block_index
     }
 
+    #[inline]
+    fn heap_memory_usage(&self) -> usize {
+        if self.big_small.is_big(){
+            core::mem::size_of::<BlockIndices>()
+        } else {
+            0
+        }
+    }
+
     #[inline]
     unsafe fn remove_unchecked(&mut self, index: usize) {
         let prev = self.mask.set_bit::<false>(index);
@@ -0,0 +1,136 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use crate::BitSetInterface;
+use crate::bit_block::BitBlock;
+use crate::internals::impl_bitset;
+use crate::bitset_interface::{BitSetBase, LevelMasks, LevelMasksIterExt};
+use crate::config::Config;
+
+/// Complement of bitset, as lazy bitset.
+/// 
+/// Contains all indices, addressable by `S::Conf` hierarchy, which are not in `S`.
+/// 
+/// Hierarchy is "full", so this is not [TRUSTED_HIERARCHY]. Iterating it
+/// on its own visits every data block of `Conf` - use it as an operand of
+/// intersection instead: `apply(And, a, ComplementBitSet::new(b))` is `a - b`.
+/// 
+/// [TRUSTED_HIERARCHY]: BitSetBase::TRUSTED_HIERARCHY
+#[derive(Clone)]
+pub struct ComplementBitSet<S>{
+    pub(crate) s: S,
+}
+impl<S> ComplementBitSet<S>{
+    #[inline]
+    pub fn new(s: S) -> Self{
+        Self{ s }
+    }
+}
+
+impl<S: LevelMasks> BitSetBase for ComplementBitSet<S>{
+    type Conf = S::Conf;
+    const TRUSTED_HIERARCHY: bool = false;
+}
+
+impl<S: LevelMasks> LevelMasks for ComplementBitSet<S>{
+    #[inline]
+    fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
+        BitBlock::ones()
+    }
+
+    #[inline]
+    unsafe fn level1_mask(&self, _: usize)
+        -> <Self::Conf as Config>::Level1BitBlock
+    {
+        BitBlock::ones()
+    }
+
+    #[inline]
+    unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
+        -> <Self::Conf as Config>::DataBitBlock
+    {
+        self.s.data_mask(level0_index, level1_index) ^ BitBlock::ones()
+    }
+    
+    #[inline]
+    fn memory_usage_estimate(&self) -> usize {
+        self.s.memory_usage_estimate()
+    }
+}
+
+impl<S: LevelMasksIterExt> LevelMasksIterExt for ComplementBitSet<S>{
+    type Level1BlockData = S::Level1BlockData;
+
+    type IterState = S::IterState;
+
+    #[inline]
+    fn make_iter_state(&self) -> Self::IterState {
+        self.s.make_iter_state()
+    }
+
+    #[inline]
+    unsafe fn drop_iter_state(&self, state: &mut ManuallyDrop<Self::IterState>) {
+        self.s.drop_iter_state(state)
+    }
+
+    #[inline]
+    unsafe fn init_level1_block_data(
+        &self,
+        state: &mut Self::IterState,
+        level1_block_data: &mut MaybeUninit<Self::Level1BlockData>,
+        level0_index: usize
+    ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
+        // Data blocks, absent in S, will be empty.
+        self.s.init_level1_block_data(state, level1_block_data, level0_index);
+        (BitBlock::ones(), true)
+    }
+
+    #[inline]
+    unsafe fn data_mask_from_block_data(
+        level1_block_data: &Self::Level1BlockData, level1_index: usize
+    ) -> <Self::Conf as Config>::DataBitBlock {
+        S::data_mask_from_block_data(level1_block_data, level1_index) ^ BitBlock::ones()
+    }
+}
+
+impl_bitset!(
+    impl<S> for ComplementBitSet<S> where S: BitSetInterface
+);
+
+#[cfg(test)]
+mod test{
+    use itertools::assert_equal;
+    use crate::{apply, BitSet, BitSetInterface, reduce};
+    use crate::config::{_64bit, max_addressable_index};
+    use crate::ops::{And, Or, Sub};
+    use super::ComplementBitSet;
+
+    type HiSparseBitset = BitSet<_64bit>;
+
+    #[test]
+    fn complement_test(){
+        let set1: HiSparseBitset = [1, 2, 100, 5000, 5001, 100_000].into_iter().collect();
+        let set2: HiSparseBitset = [2, 3, 5000, 200_000].into_iter().collect();
+        
+        let not_set2 = ComplementBitSet::new(&set2);
+        assert!(!not_set2.contains(2));
+        assert!(not_set2.contains(1));
+        assert!(not_set2.contains(0));
+        assert!(!not_set2.is_empty());
+        
+        let and_not = apply(And, &set1, ComplementBitSet::new(&set2));
+        assert_equal(and_not.iter(), apply(Sub, &set1, &set2).iter());
+        assert_eq!(and_not, apply(Sub, &set1, &set2));
+        
+        // De Morgan
+        let or = reduce(Or, [&set1, &set2].into_iter()).unwrap();
+        let not_or = ComplementBitSet::new(&or);
+        let and_nots = apply(And, ComplementBitSet::new(&set1), ComplementBitSet::new(&set2));
+        assert_eq!(not_or, and_nots);
+        
+        // Full iteration
+        let max = max_addressable_index::<_64bit>();
+        let empty = HiSparseBitset::new();
+        let full = ComplementBitSet::new(&empty);
+        assert_eq!(full.iter().count(), max);
+        assert_eq!(ComplementBitSet::new(&set1).iter().count(), max - set1.iter().count());
+    }
+}
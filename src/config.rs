@@ -9,8 +9,10 @@
 //!
 //! [BitSet]: crate::BitSet
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 use crate::bit_block::BitBlock;
+#[cfg(feature = "simd")]
+use crate::bit_block::U64x8;
 use crate::cache;
 use crate::cache::ReduceCache;
 use crate::primitive_array::PrimitiveArray;
@@ -72,6 +74,96 @@ pub(crate) const fn max_addressable_index<Conf: Config>() -> usize {
         * (1 << Conf::DataBitBlock::SIZE_POT_EXPONENT)
 }
 
+/// Max usize, [BitSet] with this `Conf` can hold.
+/// 
+/// Same as [BitSet::max_capacity()].
+/// 
+/// [BitSet]: crate::BitSet
+/// [BitSet::max_capacity()]: crate::BitSet::max_capacity()
+#[inline]
+pub const fn max_capacity<Conf: Config>() -> usize {
+    // We occupy one block for "empty" at each level, except root.
+    max_addressable_index::<Conf>()
+        - (1 << Conf::Level1BitBlock::SIZE_POT_EXPONENT) * (1 << Conf::DataBitBlock::SIZE_POT_EXPONENT)
+        - (1 << Conf::DataBitBlock::SIZE_POT_EXPONENT)
+}
+
+/// Checks `Conf` consistency. Panics, if `Conf` is invalid.
+/// 
+/// * Levels should address no more than `usize::MAX` indices.
+/// * Level bitblocks should be exactly `1 << SIZE_POT_EXPONENT` bits.
+/// * Block indices containers should accommodate whole level bitblock,
+///   and their items should be able to store max block index.
+/// 
+/// Called at compile time for each `Conf` [BitSet] or [SmallBitSet] 
+/// is instantiated with. Can be used to check custom config upfront:
+/// ```
+/// # use hi_sparse_bitset::config::{assert_valid_config, _128bit};
+/// const _: () = assert_valid_config::<_128bit>();
+/// ```
+/// 
+/// Invalid config does not compile:
+/// ```compile_fail
+/// # use hi_sparse_bitset::config::{assert_valid_config, Config};
+/// # use hi_sparse_bitset::cache::FixedCache;
+/// struct Invalid;
+/// impl Config for Invalid {
+///     type Level0BitBlock = u64;
+///     type Level0BlockIndices = [u8; 32];   // should be at least [u8; 64]
+///     type Level1BitBlock = u64;
+///     type Level1BlockIndices = [u16; 64];
+///     type DataBitBlock = u64;
+///     type DefaultCache = FixedCache<32>;
+/// }
+/// const _: () = assert_valid_config::<Invalid>();
+/// ```
+/// 
+/// [BitSet]: crate::BitSet
+/// [SmallBitSet]: crate::SmallBitSet
+pub const fn assert_valid_config<Conf: Config>() {
+    use core::mem::size_of;
+    let level0_exp = Conf::Level0BitBlock::SIZE_POT_EXPONENT;
+    let level1_exp = Conf::Level1BitBlock::SIZE_POT_EXPONENT;
+    let data_exp   = Conf::DataBitBlock::SIZE_POT_EXPONENT;
+    
+    if level0_exp + level1_exp + data_exp > usize::BITS as usize - 1 {
+        panic!("Config levels address more than usize::MAX indices.");
+    }
+    
+    if size_of::<Conf::Level0BitBlock>() * 8 != 1 << level0_exp
+    || size_of::<Conf::Level1BitBlock>() * 8 != 1 << level1_exp
+    || size_of::<Conf::DataBitBlock>() * 8 != 1 << data_exp
+    {
+        panic!("Config bitblock size does not match its SIZE_POT_EXPONENT.");
+    }
+    
+    // Level0 points to level1 blocks. Block 0 is preallocated empty block, 
+    // so there can be Level0BitBlock::size() - 1 actual ones (see max_capacity()).
+    if <Conf::Level0BlockIndices as PrimitiveArray>::CAP < 1 << level0_exp {
+        panic!("Config::Level0BlockIndices can not accommodate Level0BitBlock::size() items.");
+    }
+    let level0_item_bits = size_of::<<Conf::Level0BlockIndices as PrimitiveArray>::Item>() * 8;
+    if level0_item_bits < level0_exp {
+        panic!("Config::Level0BlockIndices item can not store Level0BitBlock::size() - 1 integer.");
+    }
+    
+    // Level1 points to data blocks. Same as with level0 - 
+    // max block index is Level0BitBlock::size() * Level1BitBlock::size() - 1.
+    if <Conf::Level1BlockIndices as PrimitiveArray>::CAP < 1 << level1_exp {
+        panic!("Config::Level1BlockIndices can not accommodate Level1BitBlock::size() items.");
+    }
+    let level1_item_bits = size_of::<<Conf::Level1BlockIndices as PrimitiveArray>::Item>() * 8;
+    if level1_item_bits < level0_exp + level1_exp {
+        panic!("Config::Level1BlockIndices item can not store Level0BitBlock::size() * Level1BitBlock::size() - 1 integer.");
+    }
+}
+
+/// Evaluates [assert_valid_config()] once per `Conf`, at compile time.
+pub(crate) struct ConfigCheck<Conf>(PhantomData<Conf>);
+impl<Conf: Config> ConfigCheck<Conf> {
+    pub(crate) const VALID: () = assert_valid_config::<Conf>();
+}
+
 /// [SmallBitSet] configuration.
 /// 
 /// Try to keep level1 block small. Remember that [Level1BitBlock] has huge align.
@@ -166,4 +258,29 @@ impl<DefaultCache: ReduceCache> Config for _256bit<DefaultCache> {
 impl<DefaultCache: ReduceCache> SmallConfig for _256bit<DefaultCache> {
     type Level1SmallBlockIndices  = [u16;14];
     type Level1MaskU64Populations = [u8;4];
-}
\ No newline at end of file
+}
+
+/// MAX = 134_217_728
+#[cfg(feature = "simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
+#[derive(Default)]
+pub struct _512bit<DefaultCache: ReduceCache = self::DefaultCache>(PhantomData<DefaultCache>);
+#[cfg(feature = "simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
+impl<DefaultCache: ReduceCache> Config for _512bit<DefaultCache> {
+    type Level0BitBlock = U64x8;
+    type Level0BlockIndices = [u16; 512];
+
+    type Level1BitBlock = U64x8;
+    type Level1BlockIndices = [u32; 512];
+
+    type DataBitBlock = U64x8;
+
+    type DefaultCache = DefaultCache;
+}
+#[cfg(feature = "simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
+impl<DefaultCache: ReduceCache> SmallConfig for _512bit<DefaultCache> {
+    type Level1SmallBlockIndices  = [u32;14];
+    type Level1MaskU64Populations = [u8;8];
+}
@@ -17,12 +17,223 @@ macro_rules! derive_raw {
                 Default::default()
             }
             
+            /// Constructs empty bitset, with enough memory preallocated to hold 
+            /// `data_blocks` data blocks without reallocations.
+            /// 
+            /// Each data block holds up to [DataBitBlock]::size() consecutive indices.
+            /// 
+            /// [DataBitBlock]: crate::config::Config::DataBitBlock
+            #[inline]
+            pub fn with_capacity_hint(data_blocks: usize) -> Self {
+                Self(<$raw>::with_capacity_hint(data_blocks))
+            }
+            
+            /// Constructs bitset with indices from `range`, for which `f` returns true.
+            /// 
+            /// Each data block is filled at once, and inserted into hierarchy
+            /// only if non-empty. Faster than collecting filtered range.
+            /// 
+            /// # Safety
+            /// 
+            /// Will panic, if `range` is out of [max_capacity()] range.
+            /// 
+            /// [max_capacity()]: Self::max_capacity
+            #[inline]
+            pub fn from_fn<R, F>(range: R, f: F) -> Self
+            where
+                R: core::ops::RangeBounds<usize>,
+                F: FnMut(usize) -> bool
+            {
+                Self(<$raw>::from_fn(range, f))
+            }
+            
+            /// Bitset from sorted indices.
+            /// 
+            /// Faster than [FromIterator] - indices of the same data block 
+            /// are inserted at once, with a single hierarchy traversal.
+            /// 
+            /// # Safety
+            /// 
+            /// Will panic, if any index is out of [max_capacity()] range.
+            /// `indices` must be sorted - this is checked only in debug builds.
+            /// 
+            /// [max_capacity()]: Self::max_capacity
+            #[inline]
+            pub fn from_sorted_slice(indices: &[usize]) -> Self {
+                Self(<$raw>::from_sorted_slice(indices))
+            }
+            
+            /// Inserts all `indices`, streaming.
+            /// 
+            /// Same as [from_sorted_slice()], but adds to existing set, and does
+            /// not need all indices upfront. Faster than [Extend] - indices of 
+            /// the same data block are inserted at once, with a single hierarchy traversal.
+            /// 
+            /// # Safety
+            /// 
+            /// Will panic, if any index is out of [max_capacity()] range.
+            /// `indices` must be sorted - this is checked only in debug builds.
+            /// 
+            /// [from_sorted_slice()]: Self::from_sorted_slice
+            /// [max_capacity()]: Self::max_capacity
+            #[inline]
+            pub fn union_with_sorted_iter<I>(&mut self, indices: I)
+            where
+                I: IntoIterator<Item = usize>
+            {
+                self.0.union_with_sorted_iter(indices)
+            }
+            
+            /// Bitset with all indices of `ranges`. 
+            /// 
+            /// Inverse of [iter_ranges()] - `from_ranges(set.iter_ranges())`
+            /// reconstructs `set`.
+            /// 
+            /// # Safety
+            /// 
+            /// Will panic, if any range is out of [max_capacity()] range.
+            /// 
+            /// [iter_ranges()]: crate::BitSetInterface::iter_ranges()
+            /// [max_capacity()]: Self::max_capacity
+            #[inline]
+            pub fn from_ranges<I>(ranges: I) -> Self
+            where
+                I: IntoIterator<Item = core::ops::RangeInclusive<usize>>
+            {
+                Self(<$raw>::from_ranges(ranges))
+            }
+            
+            /// Same as materialized [reduce()], but for `sets` slice.
+            /// 
+            /// Does not use [ReduceCache] - works with any `sets` len.
+            /// `sets` are accessed in hierarchy order: all `sets` level1 blocks of 
+            /// the same level0 index, before moving to the next one.
+            /// Empty `sets` produce empty bitset.
+            /// 
+            /// [reduce()]: crate::reduce()
+            /// [ReduceCache]: crate::cache::ReduceCache
+            #[inline]
+            pub fn apply_batch<Op, S>(op: Op, sets: &[S]) -> Self
+            where
+                Op: $crate::ops::BitSetOp,
+                for<'a> &'a S: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                Self(<$raw>::apply_batch(op, sets))
+            }
+            
+            /// Dense bitfield: bit `index % 64` of word `index / 64` is set 
+            /// for each index in bitset.
+            /// 
+            /// Length is `ceil((last + 1) / 64)`. Non-empty data blocks are
+            /// copied as is.
+            #[inline]
+            pub fn to_raw_words(&self) -> alloc::vec::Vec<u64> {
+                self.0.to_raw_words()
+            }
+            
+            /// Constructs bitset from dense bitfield, produced by [to_raw_words()].
+            /// 
+            /// Only non-zero data blocks are inserted.
+            /// 
+            /// # Safety
+            /// 
+            /// Will panic, if any set bit is out of [max_capacity()] range.
+            /// 
+            /// [to_raw_words()]: Self::to_raw_words
+            /// [max_capacity()]: Self::max_capacity
+            #[inline]
+            pub fn from_raw_words(words: &[u64]) -> Self {
+                Self(<$raw>::from_raw_words(words))
+            }
+            
+            /// Writes bitset to `writer` block by block, in compact hierarchical
+            /// binary format with header.
+            /// 
+            /// Words are written in batches - unbuffered `writer` is fine.
+            /// 
+            /// See [crate-level docs](crate#binary-format).
+            #[cfg(feature = "std")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+            #[inline]
+            pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                self.0.write_to(writer)
+            }
+            
+            /// Reads bitset, written by [write_to()].
+            /// 
+            /// Fails with [InvalidData], if stream was written by bitset
+            /// with different `Conf` level sizes.
+            /// 
+            /// `reader` is read word by word - wrap unbuffered one into [BufReader].
+            /// 
+            /// [write_to()]: Self::write_to
+            /// [InvalidData]: std::io::ErrorKind::InvalidData
+            /// [BufReader]: std::io::BufReader
+            #[cfg(feature = "std")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+            #[inline]
+            pub fn read_from<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                <$raw>::read_from(reader).map(Self)
+            }
+            
+            /// Serialize in compact hierarchical binary format.
+            /// 
+            /// See [crate-level docs](crate#binary-format).
+            #[cfg(feature = "bincode")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+            #[inline]
+            pub fn serialize_bincode(&self) -> alloc::vec::Vec<u8> {
+                self.0.serialize_bincode()
+            }
+            
+            /// Deserialize from [serialize_bincode()] output.
+            /// 
+            /// [serialize_bincode()]: Self::serialize_bincode
+            #[cfg(feature = "bincode")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+            #[inline]
+            pub fn deserialize_bincode(bytes: &[u8]) -> Result<Self, $crate::BinaryDecodeError> {
+                <$raw>::deserialize_bincode(bytes).map(Self)
+            }
+            
+            /// Splits into indices `< index` and indices `>= index`.
+            /// 
+            /// Data blocks are copied as is, except the one containing `index`.
+            #[inline]
+            pub fn split_at(&self, index: usize) -> (Self, Self) {
+                let (left, right) = self.0.split_at(index);
+                (Self(left), Self(right))
+            }
+            
+            /// Mutations counter. 
+            /// 
+            /// Incremented by each insert, remove or bulk mutation - even if it
+            /// did not actually change the set. If generation did not change - 
+            /// bitset was not mutated, and saved cursors/query results are still
+            /// actual.
+            /// 
+            /// Clone starts from 0. Not used in comparison or hashing.
+            #[inline]
+            pub fn generation(&self) -> u64 {
+                self.0.generation()
+            }
+            
             /// Max usize, bitset with this `Conf` can hold.
             #[inline]
             pub const fn max_capacity() -> usize {
                 <$raw>::max_capacity()
             }
             
+            /// Same as [max_capacity()]. Usable in `const` context:
+            /// ```
+            /// # use hi_sparse_bitset::{BitSet, config};
+            /// const MAX_ENTITY_ID: usize = BitSet::<config::_64bit>::MAX_CAPACITY;
+            /// static ENTITIES: [u8; MAX_ENTITY_ID / 64] = [0; MAX_ENTITY_ID / 64];
+            /// ```
+            /// 
+            /// [max_capacity()]: Self::max_capacity
+            pub const MAX_CAPACITY: usize = <$raw>::max_capacity();
+            
             /// # Safety
             ///
             /// Will panic, if `index` is out of range.    
@@ -31,6 +242,45 @@ macro_rules! derive_raw {
                 self.0.insert(index)
             }
             
+            /// Inserts whole data block at once.
+            /// 
+            /// `block` bits are OR-ed into existing data block. Hierarchy path
+            /// for `block.start_index` is created, if absent. Empty `block` is no-op.
+            /// 
+            /// `block.start_index` should be aligned to data block size - as 
+            /// blocks returned by [block_iter()].
+            /// 
+            /// # Safety
+            ///
+            /// Will panic, if `block.start_index` is out of range.
+            /// 
+            /// [block_iter()]: crate::BitSetInterface::block_iter()
+            #[inline]
+            pub fn insert_datablock(&mut self, block: $crate::DataBlock<<<Self as $crate::BitSetBase>::Conf as $crate::config::Config>::DataBitBlock>){
+                self.0.insert_datablock(block)
+            }
+
+            /// Removes data block, containing `index`, with all its bits.
+            ///
+            /// Returns false, if there were no such data block.
+            #[inline]
+            pub fn remove_datablock(&mut self, index: usize) -> bool {
+                self.0.remove_datablock(index)
+            }
+
+            /// `block` bits are XOR-ed into existing data block.
+            ///
+            /// Flips whole data block region at once. Data block that
+            /// became empty is removed. Empty `block` is no-op.
+            ///
+            /// # Safety
+            ///
+            /// Will panic, if `block.start_index` is out of range.
+            #[inline]
+            pub fn toggle_datablock(&mut self, block: $crate::DataBlock<<<Self as $crate::BitSetBase>::Conf as $crate::config::Config>::DataBitBlock>){
+                self.0.toggle_datablock(block)
+            }
+            
             /// Returns false if index is invalid/not in bitset.
             #[inline]
             pub fn remove(&mut self, index: usize) -> bool {
@@ -46,6 +296,266 @@ macro_rules! derive_raw {
                 let ok = self.remove(index);
                 unsafe{ $crate::assume!(ok); }
             }
+            
+            /// Removes all elements.
+            /// 
+            /// Keeps allocated memory, like [Vec::clear()]. 
+            /// O(N) of allocated blocks.
+            #[inline]
+            pub fn clear(&mut self) {
+                self.0.clear()
+            }
+            
+            /// Swaps content with `other`. O(1), same as [core::mem::swap].
+            #[inline]
+            pub fn swap(&mut self, other: &mut Self) {
+                core::mem::swap(self, other)
+            }
+            
+            /// Moves all elements into `target`, leaving `self` empty.
+            /// 
+            /// If `target` is empty - sets are just [swap]ped, in O(1).
+            /// Otherwise, data blocks are OR-ed into `target`, as with [union_update()],
+            /// and `self` is [clear]ed, keeping its allocated memory.
+            /// 
+            /// [union_update()]: Self::union_update
+            /// [swap]: Self::swap
+            /// [clear]: Self::clear
+            #[inline]
+            pub fn move_into(&mut self, target: &mut Self) {
+                if $crate::internals::is_empty(&target.0) {
+                    self.swap(target);
+                } else {
+                    target.0.union_update(&self.0);
+                    self.clear();
+                }
+            }
+            
+            /// Memory usage breakdown.
+            #[inline]
+            pub fn memory_usage(&self) -> $crate::MemoryUsage {
+                self.0.memory_usage()
+            }
+            
+            /// Hierarchy statistics: levels fill and data blocks occupancy.
+            /// 
+            /// Useful for choosing [Config] and detecting fragmentation.
+            /// O(N) of blocks in use.
+            /// 
+            /// [Config]: crate::config::Config
+            #[inline]
+            pub fn level_stats(&self) -> $crate::LevelStats {
+                self.0.level_stats()
+            }
+            
+            /// Raised bits count at each hierarchy level: 
+            /// `[level0, sum of level1 blocks, sum of data blocks]`.
+            /// 
+            /// Shows how much work hierarchy traversal does. Last one is 
+            /// the number of elements. O(N) of blocks in use.
+            /// 
+            /// See also [level_stats()].
+            /// 
+            /// [level_stats()]: Self::level_stats
+            #[inline]
+            pub fn population_count_by_level(&self) -> [usize; 3] {
+                self.0.population_count_by_level()
+            }
+            
+            /// Releases memory of removed blocks.
+            /// 
+            /// Bitset does not release memory on [remove()] - removed blocks are 
+            /// kept for later reuse. This compacts all alive blocks into new 
+            /// exactly-sized storage. O(N) of alive blocks.
+            /// 
+            /// [remove()]: Self::remove
+            #[inline]
+            pub fn shrink_to_fit(&mut self) {
+                self.0.shrink_to_fit()
+            }
+            
+            /// Renumbers blocks to be contiguous and ordered, keeping allocated memory.
+            /// 
+            /// After a lot of inserts and removes, blocks became scattered 
+            /// across storage, and iteration jumps around in memory. 
+            /// This rebuilds storage with blocks laid out in index order - 
+            /// the same way as a freshly filled bitset has. 
+            /// Unlike [shrink_to_fit()], does not release memory of removed blocks.
+            /// O(N) of alive blocks.
+            /// 
+            /// [shrink_to_fit()]: Self::shrink_to_fit
+            #[inline]
+            pub fn compact(&mut self) {
+                self.0.compact()
+            }
+            
+            /// Removes and returns the smallest element.
+            /// 
+            /// Same as [first()] followed by [remove()], but with a single
+            /// hierarchy descent.
+            /// 
+            /// [first()]: Self::first
+            /// [remove()]: Self::remove
+            #[inline]
+            pub fn pop_first(&mut self) -> Option<usize> {
+                self.0.pop_first()
+            }
+            
+            /// Removes and returns the largest element.
+            /// 
+            /// Same as [last()] followed by [remove()], but with a single
+            /// hierarchy descent.
+            /// 
+            /// [last()]: Self::last
+            /// [remove()]: Self::remove
+            #[inline]
+            pub fn pop_last(&mut self) -> Option<usize> {
+                self.0.pop_last()
+            }
+            
+            /// See [BitSetInterface::par_block_iter()]
+            /// 
+            /// [BitSetInterface::par_block_iter()]: crate::BitSetInterface::par_block_iter()
+            #[cfg(feature = "rayon")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+            #[inline]
+            pub fn par_block_iter(&self) -> $crate::iter::ParBlockIter<&'_ Self>
+            where
+                Self: Sync
+            {
+                $crate::iter::ParBlockIter::new(self)
+            }
+            
+            /// See [BitSetInterface::par_iter()]
+            /// 
+            /// [BitSetInterface::par_iter()]: crate::BitSetInterface::par_iter()
+            #[cfg(feature = "rayon")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+            #[inline]
+            pub fn par_iter(&self) -> $crate::iter::ParIndexIter<&'_ Self>
+            where
+                Self: Sync
+            {
+                $crate::iter::ParIndexIter::new(self)
+            }
+            
+            /// Retains only elements for which `f` returns true.
+            /// 
+            /// Works on data blocks level - each data block updated at once.
+            /// Emptied blocks are removed from hierarchy.
+            #[inline]
+            pub fn retain<F>(&mut self, f: F)
+            where
+                F: FnMut(usize) -> bool
+            {
+                self.0.retain(f)
+            }
+            
+            /// Retains only elements within `range`.
+            /// 
+            /// Same as `&= RangeBitSet::new(range)`, in place. Blocks outside
+            /// of range are removed from hierarchy, edge blocks are masked.
+            /// 
+            /// [RangeBitSet]: crate::RangeBitSet
+            #[inline]
+            pub fn retain_range<R>(&mut self, range: R)
+            where
+                R: core::ops::RangeBounds<usize>
+            {
+                self.0.retain_range(range)
+            }
+            
+            /// Flips all indices within `range`: raised become unset, unset become raised.
+            /// 
+            /// Same as `^= RangeBitSet::new(range)`, in place. Data blocks are 
+            /// XOR-ed as a whole - O(range size / data block size). Data blocks
+            /// that became empty are removed from hierarchy.
+            /// 
+            /// `range` is clamped to [max_capacity()].
+            /// 
+            /// [max_capacity()]: Self::max_capacity
+            #[inline]
+            pub fn xor_assign_range<R>(&mut self, range: R)
+            where
+                R: core::ops::RangeBounds<usize>
+            {
+                self.0.xor_assign_range(range)
+            }
+            
+            /// Non-empty data blocks as `(start_index, words)`, in ascending order.
+            /// 
+            /// `words` are data block's [BitBlock] `u64`s, borrowed from bitset
+            /// storage - [DataBitBlock]::size() / 64 words.
+            /// Bit `i` of word `w` is index `start_index + w * 64 + i`.
+            /// 
+            /// [BitBlock]: crate::BitBlock
+            /// [DataBitBlock]: crate::config::Config::DataBitBlock
+            #[inline]
+            pub fn iter_blocks_as_slices(&self) -> $crate::iter::BlockSlicesIter<'_, Self> {
+                $crate::iter::BlockSlicesIter::new(self, |this, start_index| unsafe{
+                    this.0.data_block_words_unchecked(start_index)
+                })
+            }
+            
+            /// Mutable counterpart of [iter_blocks_as_slices()].
+            /// 
+            /// `f(start_index, words)` is called for each non-empty data block,
+            /// in ascending order. Data blocks, that `f` made empty, are removed
+            /// from hierarchy after `f` call - that's why this is not an iterator.
+            /// 
+            /// [iter_blocks_as_slices()]: Self::iter_blocks_as_slices
+            #[inline]
+            pub fn for_each_block_slice_mut<F>(&mut self, f: F)
+            where
+                F: FnMut(usize, &mut [u64])
+            {
+                self.0.for_each_block_slice_mut(f)
+            }
+            
+            /// In-place intersection. Same as `&=`.
+            /// 
+            /// Traverse `self` data blocks - O(`self` data blocks). Blocks, 
+            /// absent in `other`, are removed as a whole, others are AND-ed.
+            #[inline]
+            pub fn intersection_update<Rhs>(&mut self, other: Rhs)
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                self.0.intersection_update(other)
+            }
+            
+            /// In-place union. Same as `|=`.
+            /// 
+            /// `other` data blocks are OR-ed in as a whole.
+            #[inline]
+            pub fn union_update<Rhs>(&mut self, other: Rhs)
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                self.0.union_update(other)
+            }
+            
+            /// In-place difference. Same as `-=`.
+            /// 
+            /// Traverse `self` data blocks. Emptied blocks are removed.
+            #[inline]
+            pub fn difference_update<Rhs>(&mut self, other: Rhs)
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                self.0.difference_update(other)
+            }
+            
+            /// In-place symmetric difference. Same as `^=`.
+            /// 
+            /// `other` data blocks are XOR-ed in as a whole. Emptied blocks are removed.
+            #[inline]
+            pub fn symmetric_difference_update<Rhs>(&mut self, other: Rhs)
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                self.0.symmetric_difference_update(other)
+            }
         }
         
         impl<$($generics),*> Clone for $t
@@ -78,6 +588,55 @@ macro_rules! derive_raw {
             }
         }
         
+        impl<$($generics),*> Extend<usize> for $t
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn extend<T: IntoIterator<Item=usize>>(&mut self, iter: T) {
+                self.0.extend(iter)
+            }
+        }
+        
+        impl<'a, $($generics),*> Extend<&'a usize> for $t
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn extend<T: IntoIterator<Item=&'a usize>>(&mut self, iter: T) {
+                self.0.extend(iter)
+            }
+        }
+        
+        impl<$($generics),*> Extend<$crate::DataBlock<<<Self as $crate::BitSetBase>::Conf as $crate::config::Config>::DataBitBlock>> for $t
+        where
+            $($where_bounds)*
+        {
+            /// Blocks are OR-ed into existing data blocks.
+            #[inline]
+            fn extend<T>(&mut self, iter: T)
+            where
+                T: IntoIterator<Item=$crate::DataBlock<<<Self as $crate::BitSetBase>::Conf as $crate::config::Config>::DataBitBlock>>
+            {
+                self.0.extend(iter)
+            }
+        }
+        
+        impl<$($generics),*> FromIterator<$crate::DataBlock<<<Self as $crate::BitSetBase>::Conf as $crate::config::Config>::DataBitBlock>> for $t
+        where
+            $($where_bounds)*
+        {
+            /// Each block is inserted as a whole, with a single hierarchy traversal.
+            /// Blocks with the same `start_index` are OR-ed.
+            #[inline]
+            fn from_iter<T>(iter: T) -> Self
+            where
+                T: IntoIterator<Item=$crate::DataBlock<<<Self as $crate::BitSetBase>::Conf as $crate::config::Config>::DataBitBlock>>
+            {
+                Self(<$raw>::from_iter(iter))
+            }
+        }
+        
         impl<$($generics),* , const N: usize> From<[usize; N]> for $t
         where
             $($where_bounds)*
@@ -88,6 +647,207 @@ macro_rules! derive_raw {
             }
         }
         
+        impl<$($generics),*, Rhs> core::ops::BitAndAssign<Rhs> for $t
+        where
+            Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
+            $($where_bounds)*
+        {
+            /// In-place intersection.
+            #[inline]
+            fn bitand_assign(&mut self, rhs: Rhs) {
+                self.0.intersection_update(rhs)
+            }
+        }
+        
+        impl<$($generics),*, Rhs> core::ops::BitOrAssign<Rhs> for $t
+        where
+            Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
+            $($where_bounds)*
+        {
+            /// In-place union.
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Rhs) {
+                self.0.union_update(rhs)
+            }
+        }
+        
+        impl<$($generics),*, Rhs> core::ops::BitXorAssign<Rhs> for $t
+        where
+            Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
+            $($where_bounds)*
+        {
+            /// In-place symmetric difference.
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: Rhs) {
+                self.0.symmetric_difference_update(rhs)
+            }
+        }
+        
+        impl<$($generics),*, Rhs> core::ops::SubAssign<Rhs> for $t
+        where
+            Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
+            $($where_bounds)*
+        {
+            /// In-place difference.
+            #[inline]
+            fn sub_assign(&mut self, rhs: Rhs) {
+                self.0.difference_update(rhs)
+            }
+        }
+        
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<$($generics),*> serde::Serialize for $t
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+        
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<'de, $($generics),*> serde::Deserialize<'de> for $t
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$raw as serde::Deserialize>::deserialize(deserializer).map(Self)
+            }
+        }
+        
+        #[cfg(feature = "roaring")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "roaring")))]
+        impl<$($generics),*> From<&roaring::RoaringBitmap> for $t
+        where
+            $($where_bounds)*
+        {
+            /// # Panics
+            ///
+            /// Panics, if any index is out of bitset range.
+            #[inline]
+            fn from(value: &roaring::RoaringBitmap) -> Self {
+                Self(value.iter().map(|index| index as usize).collect())
+            }
+        }
+        
+        #[cfg(feature = "roaring")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "roaring")))]
+        impl<$($generics),*> From<roaring::RoaringBitmap> for $t
+        where
+            $($where_bounds)*
+        {
+            /// # Panics
+            ///
+            /// Panics, if any index is out of bitset range.
+            #[inline]
+            fn from(value: roaring::RoaringBitmap) -> Self {
+                Self::from(&value)
+            }
+        }
+        
+        #[cfg(feature = "roaring")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "roaring")))]
+        impl<$($generics),*> From<&$t> for roaring::RoaringBitmap
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn from(value: &$t) -> Self {
+                // All configs max_capacity fit u32.
+                roaring::RoaringBitmap::from_sorted_iter(
+                    value.iter().map(|index| index as u32)
+                ).unwrap()
+            }
+        }
+        
+        #[cfg(feature = "roaring")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "roaring")))]
+        impl<$($generics),*> From<$t> for roaring::RoaringBitmap
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn from(value: $t) -> Self {
+                Self::from(&value)
+            }
+        }
+        
+        #[cfg(feature = "fixedbitset")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "fixedbitset")))]
+        impl<$($generics),*> From<&fixedbitset::FixedBitSet> for $t
+        where
+            $($where_bounds)*
+        {
+            /// Copies bitset word by word, skipping zero data blocks.
+            /// 
+            /// # Panics
+            ///
+            /// Panics, if any index is out of bitset range.
+            #[inline]
+            fn from(value: &fixedbitset::FixedBitSet) -> Self {
+                Self(<$raw>::from_fixedbitset(value))
+            }
+        }
+        
+        #[cfg(feature = "fixedbitset")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "fixedbitset")))]
+        impl<$($generics),*> From<fixedbitset::FixedBitSet> for $t
+        where
+            $($where_bounds)*
+        {
+            /// # Panics
+            ///
+            /// Panics, if any index is out of bitset range.
+            #[inline]
+            fn from(value: fixedbitset::FixedBitSet) -> Self {
+                Self::from(&value)
+            }
+        }
+        
+        #[cfg(feature = "fixedbitset")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "fixedbitset")))]
+        impl<$($generics),*> From<&$t> for fixedbitset::FixedBitSet
+        where
+            $($where_bounds)*
+        {
+            /// Copies data blocks word by word. Result length is `last + 1`.
+            #[inline]
+            fn from(value: &$t) -> Self {
+                value.0.to_fixedbitset()
+            }
+        }
+        
+        #[cfg(feature = "fixedbitset")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "fixedbitset")))]
+        impl<$($generics),*> From<$t> for fixedbitset::FixedBitSet
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn from(value: $t) -> Self {
+                Self::from(&value)
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+        impl<$($generics),*> proptest::arbitrary::Arbitrary for $t
+        where
+            $($where_bounds)*
+        {
+            type Parameters = $crate::ArbitraryParams;
+            type Strategy = crate::proptest::ArbitraryStrategy<Self>;
+
+            #[inline]
+            fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+                crate::proptest::arbitrary_bitset::<<Self as $crate::BitSetBase>::Conf, Self>(params)
+            }
+        }
+
         crate::derive_raw::derive_raw_levelmasks!(
             impl<$($generics),*> $t as $raw where $($where_bounds)*  
         );
@@ -123,7 +883,12 @@ macro_rules! derive_raw_levelmasks {
             #[inline]
             unsafe fn data_mask(&self, level0_index: usize, level1_index: usize) -> <Self::Conf as Config>::DataBitBlock {
                 self.0.data_mask(level0_index, level1_index)
-            }            
+            }
+            
+            #[inline]
+            fn memory_usage_estimate(&self) -> usize {
+                self.0.approximate_memory_usage()
+            }
         }
         
         impl<$($generics),*> $crate::internals::LevelMasksIterExt for $t
@@ -139,7 +904,7 @@ macro_rules! derive_raw_levelmasks {
             }
         
             #[inline]
-            unsafe fn drop_iter_state(&self, state: &mut std::mem::ManuallyDrop<Self::IterState>) {
+            unsafe fn drop_iter_state(&self, state: &mut core::mem::ManuallyDrop<Self::IterState>) {
                 self.0.drop_iter_state(state)
             }
         
@@ -147,7 +912,7 @@ macro_rules! derive_raw_levelmasks {
             unsafe fn init_level1_block_data(
                 &self, 
                 state: &mut Self::IterState, 
-                level1_block_data: &mut std::mem::MaybeUninit<Self::Level1BlockData>, 
+                level1_block_data: &mut core::mem::MaybeUninit<Self::Level1BlockData>, 
                 level0_index: usize
             ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
                 self.0.init_level1_block_data(state, level1_block_data, level0_index)
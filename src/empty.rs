@@ -0,0 +1,97 @@
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use crate::bit_block::BitBlock;
+use crate::internals::impl_bitset;
+use crate::bitset_interface::{BitSetBase, LevelMasks, LevelMasksIterExt};
+use crate::config::Config;
+
+/// Bitset without elements.
+/// 
+/// Zero-sized. Can be used as identity element of [Or]/[Xor] operations,
+/// or as a placeholder.
+/// 
+/// [Or]: crate::ops::Or
+/// [Xor]: crate::ops::Xor
+pub struct EmptyBitSet<Conf>(PhantomData<Conf>);
+
+impl<Conf> EmptyBitSet<Conf>{
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Conf> Default for EmptyBitSet<Conf>{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Conf> Clone for EmptyBitSet<Conf>{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Conf> Copy for EmptyBitSet<Conf>{}
+
+impl<Conf: Config> BitSetBase for EmptyBitSet<Conf> {
+    type Conf = Conf;
+    const TRUSTED_HIERARCHY: bool = true;
+}
+
+impl<Conf: Config> LevelMasks for EmptyBitSet<Conf> {
+    #[inline]
+    fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
+        BitBlock::zero()
+    }
+
+    #[inline]
+    unsafe fn level1_mask(&self, _: usize)
+        -> <Self::Conf as Config>::Level1BitBlock
+    {
+        BitBlock::zero()
+    }
+
+    #[inline]
+    unsafe fn data_mask(&self, _: usize, _: usize)
+        -> <Self::Conf as Config>::DataBitBlock
+    {
+        BitBlock::zero()
+    }
+}
+
+impl<Conf: Config> LevelMasksIterExt for EmptyBitSet<Conf> {
+    type IterState = ();
+    type Level1BlockData = ();
+
+    #[inline]
+    fn make_iter_state(&self) -> Self::IterState {}
+    
+    #[inline]
+    unsafe fn drop_iter_state(&self, _: &mut ManuallyDrop<Self::IterState>) {}
+
+    #[inline]
+    unsafe fn init_level1_block_data(
+        &self,
+        _: &mut Self::IterState,
+        level1_block_data: &mut MaybeUninit<Self::Level1BlockData>,
+        _: usize
+    ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
+        level1_block_data.write(());
+        (BitBlock::zero(), false)
+    }
+
+    #[inline]
+    unsafe fn data_mask_from_block_data(
+        _: &Self::Level1BlockData, _: usize
+    ) -> <Self::Conf as Config>::DataBitBlock {
+        BitBlock::zero()
+    }
+}
+
+impl_bitset!(
+    impl<Conf> for EmptyBitSet<Conf> where Conf: Config
+);
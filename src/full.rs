@@ -0,0 +1,198 @@
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use crate::bit_block::BitBlock;
+use crate::internals::impl_bitset;
+use crate::bitset_interface::{BitSetBase, LevelMasks, LevelMasksIterExt};
+use crate::config::{Config, max_capacity};
+use crate::level_indices;
+
+/// Bitset with all indices in `0..max_capacity()`.
+/// 
+/// Zero-sized. Universe set - identity element of [And] operation.
+/// Contains exactly the same indices, that [BitSet]/[SmallBitSet] with the
+/// same `Conf` can hold.
+/// 
+/// [And]: crate::ops::And
+/// [BitSet]: crate::BitSet
+/// [SmallBitSet]: crate::SmallBitSet
+pub struct FullBitSet<Conf>(PhantomData<Conf>);
+
+impl<Conf: Config> FullBitSet<Conf>{
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+    
+    /// Same as [BitSet::max_capacity()].
+    /// 
+    /// [BitSet::max_capacity()]: crate::BitSet::max_capacity()
+    #[inline]
+    pub const fn max_capacity() -> usize {
+        max_capacity::<Conf>()
+    }
+}
+
+impl<Conf: Config> Default for FullBitSet<Conf>{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Conf: Config> Clone for FullBitSet<Conf>{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Conf: Config> Copy for FullBitSet<Conf>{}
+
+/// Block with first `n` bits raised.
+#[inline]
+pub(crate) fn first_n_ones<B: BitBlock>(n: usize) -> B {
+    let mut block = B::zero();
+    let words = block.as_array_mut();
+    let full_words = n / 64;
+    for word in &mut words[..full_words] {
+        *word = u64::MAX;
+    }
+    let rest = n % 64;
+    if rest != 0 {
+        words[full_words] = u64::MAX >> (64 - rest);
+    }
+    block
+}
+
+/// Block, where bits before `end_index` are raised,
+/// and `end_index` bit raised if `end_partial`.
+#[inline]
+fn edge_mask<B: BitBlock>(end_index: usize, end_partial: bool) -> B {
+    first_n_ones(end_index + end_partial as usize)
+}
+
+impl<Conf: Config> BitSetBase for FullBitSet<Conf> {
+    type Conf = Conf;
+    const TRUSTED_HIERARCHY: bool = true;
+}
+
+impl<Conf: Config> LevelMasks for FullBitSet<Conf> {
+    #[inline]
+    fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
+        let (level0_end, level1_end, data_end) = level_indices::<Conf>(max_capacity::<Conf>());
+        edge_mask(level0_end, level1_end != 0 || data_end != 0)
+    }
+
+    #[inline]
+    unsafe fn level1_mask(&self, level0_index: usize)
+        -> <Self::Conf as Config>::Level1BitBlock
+    {
+        let (level0_end, level1_end, data_end) = level_indices::<Conf>(max_capacity::<Conf>());
+        if level0_index < level0_end {
+            BitBlock::ones()
+        } else if level0_index == level0_end {
+            edge_mask(level1_end, data_end != 0)
+        } else {
+            BitBlock::zero()
+        }
+    }
+
+    #[inline]
+    unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
+        -> <Self::Conf as Config>::DataBitBlock
+    {
+        let (level0_end, level1_end, data_end) = level_indices::<Conf>(max_capacity::<Conf>());
+        if (level0_index, level1_index) < (level0_end, level1_end) {
+            BitBlock::ones()
+        } else if (level0_index, level1_index) == (level0_end, level1_end) {
+            first_n_ones(data_end)
+        } else {
+            BitBlock::zero()
+        }
+    }
+}
+
+impl<Conf: Config> LevelMasksIterExt for FullBitSet<Conf> {
+    type IterState = ();
+    
+    /// level0_index
+    type Level1BlockData = usize;
+
+    #[inline]
+    fn make_iter_state(&self) -> Self::IterState {}
+    
+    #[inline]
+    unsafe fn drop_iter_state(&self, _: &mut ManuallyDrop<Self::IterState>) {}
+
+    #[inline]
+    unsafe fn init_level1_block_data(
+        &self,
+        _: &mut Self::IterState,
+        level1_block_data: &mut MaybeUninit<Self::Level1BlockData>,
+        level0_index: usize
+    ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
+        level1_block_data.write(level0_index);
+        (self.level1_mask(level0_index), true)
+    }
+
+    #[inline]
+    unsafe fn data_mask_from_block_data(
+        level0_index: &Self::Level1BlockData, level1_index: usize
+    ) -> <Self::Conf as Config>::DataBitBlock {
+        Self::new().data_mask(*level0_index, level1_index)
+    }
+}
+
+impl_bitset!(
+    impl<Conf> for FullBitSet<Conf> where Conf: Config
+);
+
+#[cfg(test)]
+mod test{
+    use itertools::assert_equal;
+    use crate::{apply, BitSet, BitSetInterface, EmptyBitSet, reduce};
+    use crate::config::{_64bit, Config};
+    use crate::ops::{And, Or};
+    use super::FullBitSet;
+
+    fn test<Conf: Config>(){
+        let full = FullBitSet::<Conf>::new();
+        let max = FullBitSet::<Conf>::max_capacity();
+        assert_eq!(max, BitSet::<Conf>::max_capacity());
+        
+        assert!(full.contains(0));
+        assert!(full.contains(max - 1));
+        assert!(!full.contains(max));
+        assert_eq!(full.first(), Some(0));
+        assert_eq!(full.last(), Some(max - 1));
+        
+        let set: BitSet<Conf> = [0, 10, 1000, max - 1].into_iter().collect();
+        assert_eq!(apply(And, &set, full), set);
+        assert_eq!(apply(Or, &set, full), full);
+        
+        let empty = EmptyBitSet::<Conf>::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(apply(Or, &set, empty), set);
+        assert!(apply(And, &set, empty).is_empty());
+        assert_eq!(reduce(Or, [empty, empty].into_iter()).unwrap(), empty);
+        
+        let mut filled: BitSet<Conf> = Default::default();
+        for i in 0..max {
+            filled.insert(i);
+        }
+        assert_eq!(filled, full);
+        assert_equal(full.iter(), 0..max);
+    }
+
+    #[test]
+    fn full_empty_64_test(){
+        test::<_64bit>();
+    }
+    
+    #[cfg(all(feature = "simd", not(miri)))]
+    #[test]
+    fn full_empty_128_test(){
+        test::<crate::config::_128bit>();
+    }
+}
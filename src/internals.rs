@@ -28,8 +28,8 @@
 //! [impl_simple_bitset!]: crate::impl_bitset_simple
 //! 
 //! ```
-//! # use std::marker::PhantomData;
-//! # use std::mem::{ManuallyDrop, MaybeUninit};
+//! # use core::marker::PhantomData;
+//! # use core::mem::{ManuallyDrop, MaybeUninit};
 //! # use hi_sparse_bitset::config::Config;
 //! # use hi_sparse_bitset::{BitBlock, BitSetBase, BitSetInterface, impl_bitset};
 //! # use hi_sparse_bitset::internals::*;
@@ -92,8 +92,11 @@
 //! * examples/custom_bitset_simple.rs
 //! * examples/custom_bitset.rs
 
-use crate::bitset_interface::{bitset_is_empty, bitsets_eq, bitset_contains};
+use core::cmp::Ordering;
+use core::hash::Hasher;
+use crate::bitset_interface::{bitset_is_empty, bitsets_eq, bitset_contains, bitset_contains_range, bitset_count_in_range, bitset_complement_bounded, bitset_exact_len, bitsets_cmp, bitset_hash, bitset_is_subset, bitset_is_disjoint, bitset_apply_size, bitset_jaccard_similarity, bitset_first, bitset_last, bitset_count_trailing_zeros, bitset_count_leading_zeros, bitset_is_contiguous, bitset_to_range, bitset_first_unset, bitset_debug_fmt, bitset_display_fmt};
 use crate::config::{DefaultBlockIterator, DefaultIndexIterator};
+use crate::iter::{BlockCursor, BoundedBlockIter, BoundedIndexIter, ChunksIter, ComplementBoundedIter, IndexCursor, ExactIndexIter, RangesIter, RevBlockIter, RevIndexIter};
 use crate::bitset_interface::BitSetInterface;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "impl")))]
@@ -140,6 +143,95 @@ where
     DefaultBlockIterator::new(set)
 }
 
+#[inline]
+pub fn block_iter_from<T>(set: &T, first: usize) -> DefaultBlockIterator<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    DefaultBlockIterator::new(set).move_to(BlockCursor::from(first))
+}
+
+#[inline]
+pub fn block_iter_until<T>(set: &T, last: usize) -> BoundedBlockIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    BoundedBlockIter::new(DefaultBlockIterator::new(set), last)
+}
+
+#[inline]
+pub fn index_iter_from<T>(set: &T, first: usize) -> DefaultIndexIterator<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    DefaultIndexIterator::new(set).move_to(IndexCursor::from(first))
+}
+
+#[inline]
+pub fn index_iter_until<T>(set: &T, last: usize) -> BoundedIndexIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    BoundedIndexIter::new(DefaultIndexIterator::new(set), last)
+}
+
+#[inline]
+pub fn index_iter_range<T, R>(set: &T, range: R) -> BoundedIndexIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface,
+    R: core::ops::RangeBounds<usize>
+{
+    BoundedIndexIter::with_range(DefaultIndexIterator::new(set), range)
+}
+
+#[inline]
+pub fn rev_block_iter<T>(set: &T) -> RevBlockIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    RevBlockIter::new(set)
+}
+
+#[inline]
+pub fn rev_index_iter<T>(set: &T) -> RevIndexIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    RevIndexIter::new(set)
+}
+
+#[inline]
+pub fn ranges_iter<T>(set: &T) -> RangesIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    RangesIter::new(set)
+}
+
+#[inline]
+pub fn complement_bounded_iter<T>(set: &T, max: usize) -> ComplementBoundedIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    DefaultIndexIterator::new(bitset_complement_bounded(set, max))
+}
+
+#[inline]
+pub fn exact_index_iter<T>(set: &T) -> ExactIndexIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    ExactIndexIter::new(set, bitset_exact_len(set))
+}
+
+#[inline]
+pub fn chunks_iter<T>(set: &T, chunk_size: usize) -> ChunksIter<&'_ T>
+where
+    for<'a> &'a T: BitSetInterface
+{
+    ChunksIter::new(set, chunk_size)
+}
+
 #[inline]
 pub fn block_iter<'a, T>(set: &'a T) -> DefaultBlockIterator<&'a T>
 where
@@ -160,6 +252,44 @@ where
     bitsets_eq(left, right)
 }
 
+/// Lexicographical comparison, same as for [BTreeSet].
+/// 
+/// [BTreeSet]: std::collections::BTreeSet
+#[inline]
+pub fn cmp<L, R>(left: L, right: R) -> Ordering
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>
+{
+    bitsets_cmp(left, right)
+}
+
+/// Hashes non-empty data blocks. Consistent with [is_eq].
+#[inline]
+pub fn hash<S, H>(bitset: S, state: &mut H)
+where
+    S: LevelMasksIterExt,
+    H: Hasher
+{
+    bitset_hash(bitset, state)
+}
+
+/// Range-compressed [Debug] output: `{1..=5, 7, 100..=102}`.
+/// 
+/// [Debug]: core::fmt::Debug
+#[inline]
+pub fn debug_fmt<S: LevelMasksIterExt>(bitset: S, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    bitset_debug_fmt(bitset, f)
+}
+
+/// Range-compressed [Display] output: `1..=5, 7, 100..=102`.
+/// 
+/// [Display]: core::fmt::Display
+#[inline]
+pub fn display_fmt<S: LevelMasksIterExt>(bitset: S, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    bitset_display_fmt(bitset, f)
+}
+
 /// O(1) for [TRUSTED_HIERARCHY].
 /// 
 /// [TRUSTED_HIERARCHY]: crate::BitSetBase::TRUSTED_HIERARCHY 
@@ -173,6 +303,137 @@ pub fn contains<S: LevelMasks>(bitset: S, index: usize) -> bool {
     bitset_contains(bitset, index)
 } 
 
+/// See [BitSetInterface::contains_range()].
+/// 
+/// [BitSetInterface::contains_range()]: crate::BitSetInterface::contains_range()
+#[inline]
+pub fn contains_range<S: LevelMasks>(bitset: S, range: core::ops::RangeInclusive<usize>) -> bool {
+    bitset_contains_range(bitset, range)
+}
+
+/// See [BitSetInterface::count_in_range()].
+/// 
+/// [BitSetInterface::count_in_range()]: crate::BitSetInterface::count_in_range()
+#[inline]
+pub fn count_in_range<S: LevelMasksIterExt>(bitset: S, range: core::ops::RangeInclusive<usize>) -> usize {
+    bitset_count_in_range(bitset, range)
+}
+
+/// See [BitSetInterface::exact_len()].
+/// 
+/// [BitSetInterface::exact_len()]: crate::BitSetInterface::exact_len()
+#[inline]
+pub fn exact_len<S: LevelMasksIterExt>(bitset: S) -> usize {
+    bitset_exact_len(bitset)
+}
+
+/// Smallest element. See [BitSetInterface::first()].
+/// 
+/// [BitSetInterface::first()]: crate::BitSetInterface::first()
+#[inline]
+pub fn first<S: LevelMasks>(bitset: S) -> Option<usize> {
+    bitset_first(bitset)
+}
+
+/// Largest element. See [BitSetInterface::last()].
+/// 
+/// [BitSetInterface::last()]: crate::BitSetInterface::last()
+#[inline]
+pub fn last<S: LevelMasks>(bitset: S) -> Option<usize> {
+    bitset_last(bitset)
+}
+
+/// See [BitSetInterface::count_trailing_zeros()].
+/// 
+/// [BitSetInterface::count_trailing_zeros()]: crate::BitSetInterface::count_trailing_zeros()
+#[inline]
+pub fn count_trailing_zeros<S: LevelMasks>(bitset: S) -> usize {
+    bitset_count_trailing_zeros(bitset)
+}
+
+/// See [BitSetInterface::count_leading_zeros()].
+/// 
+/// [BitSetInterface::count_leading_zeros()]: crate::BitSetInterface::count_leading_zeros()
+#[inline]
+pub fn count_leading_zeros<S: LevelMasks>(bitset: S) -> usize {
+    bitset_count_leading_zeros(bitset)
+}
+
+/// See [BitSetInterface::first_unset()].
+/// 
+/// [BitSetInterface::first_unset()]: crate::BitSetInterface::first_unset()
+#[inline]
+pub fn first_unset<S, R>(bitset: S, range: R) -> Option<usize>
+where
+    S: LevelMasksIterExt,
+    R: core::ops::RangeBounds<usize>
+{
+    bitset_first_unset(bitset, range)
+}
+
+/// See [BitSetInterface::is_contiguous()].
+/// 
+/// [BitSetInterface::is_contiguous()]: crate::BitSetInterface::is_contiguous()
+#[inline]
+pub fn is_contiguous<S: LevelMasksIterExt>(bitset: S) -> bool {
+    bitset_is_contiguous(bitset)
+}
+
+/// See [BitSetInterface::to_range()].
+/// 
+/// [BitSetInterface::to_range()]: crate::BitSetInterface::to_range()
+#[inline]
+pub fn to_range<S: LevelMasksIterExt>(bitset: S) -> Option<core::ops::RangeInclusive<usize>> {
+    bitset_to_range(bitset)
+}
+
+/// Does `right` contain all elements of `left`?
+#[inline]
+pub fn is_subset<L, R>(left: L, right: R) -> bool
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>
+{
+    bitset_is_subset(left, right)
+}
+
+/// Have `left` and `right` no elements in common?
+#[inline]
+pub fn is_disjoint<L, R>(left: L, right: R) -> bool
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>
+{
+    bitset_is_disjoint(left, right)
+}
+
+/// Number of elements in `apply(op, left, right)`, without materialization.
+/// 
+/// See [BitSetInterface::intersection_size()].
+/// 
+/// [BitSetInterface::intersection_size()]: crate::BitSetInterface::intersection_size()
+#[inline]
+pub fn apply_size<Op, L, R>(op: Op, left: L, right: R) -> usize
+where
+    Op: crate::ops::BitSetOp,
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>
+{
+    bitset_apply_size(op, left, right)
+}
+
+/// See [BitSetInterface::jaccard_similarity()].
+/// 
+/// [BitSetInterface::jaccard_similarity()]: crate::BitSetInterface::jaccard_similarity()
+#[inline]
+pub fn jaccard_similarity<L, R>(left: L, right: R) -> f64
+where
+    L: LevelMasksIterExt,
+    R: LevelMasksIterExt<Conf = L::Conf>
+{
+    bitset_jaccard_similarity(left, right)
+}
+
 /// Same as [impl_bitset], but for [LevelMasks].  
 /// 
 /// Implements [LevelMasksIterExt] by routing all operations to [LevelMasks].
@@ -198,7 +459,7 @@ macro_rules! impl_bitset_simple {
         {
             type IterState = ();
             
-            type Level1BlockData = (Option<std::ptr::NonNull<Self>>, usize);
+            type Level1BlockData = (Option<core::ptr::NonNull<Self>>, usize);
         
             fn make_iter_state(&self) -> Self::IterState { () }
             unsafe fn drop_iter_state(&self, state: &mut ManuallyDrop<Self::IterState>) {}
@@ -231,7 +492,7 @@ macro_rules! impl_bitset_simple {
 
 /// Makes bitset from [LevelMasksIterExt].
 /// 
-/// Implements [BitSetInterface], [IntoIterator], [Eq], [Debug], [BitAnd], [BitOr], [BitXor], [Sub]
+/// Implements [BitSetInterface], [IntoIterator], [Eq], [Ord], [Hash], [Debug], [Display], [BitAnd], [BitOr], [BitXor], [Sub], [Shr], [Not]
 /// for [LevelMasksIterExt]. Also duplicates part of BitSetInterface in struct impl,
 /// for ease of use. 
 /// 
@@ -240,10 +501,14 @@ macro_rules! impl_bitset_simple {
 /// Working only with refs will prevent T from being passed to apply/reduce
 /// as value, and will allow to store `&self` pointer safely inside [Level1BlockData].
 /// 
-/// [BitAnd]: std::ops::BitAnd
-/// [BitOr]: std::ops::BitOr
-/// [BitXor]: std::ops::BitXor
-/// [Sub]: std::ops::Sub
+/// [Hash]: core::hash::Hash
+/// [Display]: core::fmt::Display
+/// [BitAnd]: core::ops::BitAnd
+/// [BitOr]: core::ops::BitOr
+/// [BitXor]: core::ops::BitXor
+/// [Sub]: core::ops::Sub
+/// [Shr]: core::ops::Shr
+/// [Not]: core::ops::Not
 /// [BitSetInterface]: crate::BitSetInterface 
 /// [BitSet]: crate::BitSet
 /// [Level1BlockData]: LevelMasksIterExt::Level1BlockData
@@ -273,7 +538,7 @@ macro_rules! impl_bitset {
             }
         }        
         
-        impl<$($generics),*, Rhs> std::ops::BitAnd<Rhs> for $t
+        impl<$($generics),*, Rhs> core::ops::BitAnd<Rhs> for $t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as BitSetBase>::Conf>,
             $($where_bounds)*
@@ -287,7 +552,7 @@ macro_rules! impl_bitset {
             }
         }
         
-        impl<$($generics),*, Rhs> std::ops::BitOr<Rhs> for $t
+        impl<$($generics),*, Rhs> core::ops::BitOr<Rhs> for $t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as BitSetBase>::Conf>,
             $($where_bounds)*         
@@ -301,7 +566,7 @@ macro_rules! impl_bitset {
             }
         }    
         
-        impl<$($generics),*, Rhs> std::ops::BitXor<Rhs> for $t
+        impl<$($generics),*, Rhs> core::ops::BitXor<Rhs> for $t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as BitSetBase>::Conf>,
             $($where_bounds)*
@@ -315,7 +580,7 @@ macro_rules! impl_bitset {
             }
         }
         
-        impl<$($generics),*, Rhs> std::ops::Sub<Rhs> for $t
+        impl<$($generics),*, Rhs> core::ops::Sub<Rhs> for $t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as BitSetBase>::Conf>,
             $($where_bounds)*         
@@ -331,6 +596,33 @@ macro_rules! impl_bitset {
             }
         }        
         
+        impl<$($generics),*, Rhs> core::ops::Shr<Rhs> for $t
+        where
+            Rhs: $crate::BitSetInterface<Conf = <Self as BitSetBase>::Conf>,
+            $($where_bounds)*         
+        {
+            type Output = $crate::Apply<$crate::ops::Implication, Self, Rhs>;
+
+            /// Returns implication `self -> rhs`, or `!self | rhs`.
+            #[inline]
+            fn shr(self, rhs: Rhs) -> Self::Output{
+                $crate::apply($crate::ops::Implication, self, rhs)
+            }
+        }        
+        
+        impl<$($generics),*> core::ops::Not for $t
+        where
+            $($where_bounds)*         
+        {
+            type Output = $crate::ComplementBitSet<Self>;
+
+            /// Returns complement of self.
+            #[inline]
+            fn not(self) -> Self::Output{
+                $crate::ops::complement(self)
+            }
+        }        
+        
         impl_bitset!(impl<$($generics),*> for ref $t where $($where_bounds)*);
     };
     
@@ -349,17 +641,118 @@ macro_rules! impl_bitset {
             $($where_bounds)*
         {
             #[inline]
-            pub fn block_iter<'a>(&'a self) -> $crate::iter::CachingBlockIter<&'a Self> 
+            pub fn block_iter(&self) -> $crate::iter::CachingBlockIter<&'_ Self> 
             {
                 $crate::internals::block_iter(self)
             }   
             
             #[inline]
-            pub fn iter<'a>(&'a self) -> $crate::iter::CachingIndexIter<&'a Self> 
+            pub fn iter(&self) -> $crate::iter::CachingIndexIter<&'_ Self> 
             {
                 $crate::internals::index_iter(self)
             }
             
+            /// See [BitSetInterface::block_iter_from()]
+            /// 
+            /// [BitSetInterface::block_iter_from()]: crate::BitSetInterface::block_iter_from()
+            #[inline]
+            pub fn block_iter_from(&self, first: usize) -> $crate::iter::CachingBlockIter<&'_ Self> 
+            {
+                $crate::internals::block_iter_from(self, first)
+            }
+            
+            /// See [BitSetInterface::block_iter_until()]
+            /// 
+            /// [BitSetInterface::block_iter_until()]: crate::BitSetInterface::block_iter_until()
+            #[inline]
+            pub fn block_iter_until(&self, last: usize) -> $crate::iter::BoundedBlockIter<&'_ Self> 
+            {
+                $crate::internals::block_iter_until(self, last)
+            }
+            
+            /// See [BitSetInterface::iter_from()]
+            /// 
+            /// [BitSetInterface::iter_from()]: crate::BitSetInterface::iter_from()
+            #[inline]
+            pub fn iter_from(&self, first: usize) -> $crate::iter::CachingIndexIter<&'_ Self> 
+            {
+                $crate::internals::index_iter_from(self, first)
+            }
+            
+            /// See [BitSetInterface::iter_until()]
+            /// 
+            /// [BitSetInterface::iter_until()]: crate::BitSetInterface::iter_until()
+            #[inline]
+            pub fn iter_until(&self, last: usize) -> $crate::iter::BoundedIndexIter<&'_ Self> 
+            {
+                $crate::internals::index_iter_until(self, last)
+            }
+            
+            /// See [BitSetInterface::iter_range()]
+            /// 
+            /// [BitSetInterface::iter_range()]: crate::BitSetInterface::iter_range()
+            #[inline]
+            pub fn iter_range<R>(&self, range: R) -> $crate::iter::BoundedIndexIter<&'_ Self> 
+            where
+                R: core::ops::RangeBounds<usize>
+            {
+                $crate::internals::index_iter_range(self, range)
+            }
+            
+            /// See [BitSetInterface::rev_block_iter()]
+            /// 
+            /// [BitSetInterface::rev_block_iter()]: crate::BitSetInterface::rev_block_iter()
+            #[inline]
+            pub fn rev_block_iter(&self) -> $crate::iter::RevBlockIter<&'_ Self> 
+            {
+                $crate::internals::rev_block_iter(self)
+            }
+            
+            /// See [BitSetInterface::rev_iter()]
+            /// 
+            /// [BitSetInterface::rev_iter()]: crate::BitSetInterface::rev_iter()
+            #[inline]
+            pub fn rev_iter(&self) -> $crate::iter::RevIndexIter<&'_ Self> 
+            {
+                $crate::internals::rev_index_iter(self)
+            }
+            
+            /// See [BitSetInterface::iter_ranges()]
+            /// 
+            /// [BitSetInterface::iter_ranges()]: crate::BitSetInterface::iter_ranges()
+            #[inline]
+            pub fn iter_ranges(&self) -> $crate::iter::RangesIter<&'_ Self> 
+            {
+                $crate::internals::ranges_iter(self)
+            }
+            
+            /// See [BitSetInterface::iter_complement_bounded()]
+            /// 
+            /// [BitSetInterface::iter_complement_bounded()]: crate::BitSetInterface::iter_complement_bounded()
+            #[inline]
+            pub fn iter_complement_bounded(&self, max: usize) -> $crate::iter::ComplementBoundedIter<&'_ Self> 
+            {
+                $crate::internals::complement_bounded_iter(self, max)
+            }
+            
+            /// See [BitSetInterface::exact_iter()]
+            /// 
+            /// [BitSetInterface::exact_iter()]: crate::BitSetInterface::exact_iter()
+            #[inline]
+            pub fn exact_iter(&self) -> $crate::iter::ExactIndexIter<&'_ Self> 
+            {
+                $crate::internals::exact_index_iter(self)
+            }
+            
+            /// See [BitSetInterface::chunks()]
+            /// 
+            /// [BitSetInterface::chunks()]: crate::BitSetInterface::chunks()
+            #[inline]
+            pub fn chunks(&self, chunk_size: usize) -> $crate::iter::ChunksIter<&'_ Self> 
+            {
+                $crate::internals::chunks_iter(self, chunk_size)
+            }
+            
             #[inline]
             pub fn contains(&self, index: usize) -> bool {
                 $crate::internals::contains(self, index)
@@ -372,6 +765,196 @@ macro_rules! impl_bitset {
             pub fn is_empty(&self) -> bool {
                 $crate::internals::is_empty(self)
             }
+            
+            /// See [BitSetInterface::contains_range()]
+            /// 
+            /// [BitSetInterface::contains_range()]: crate::BitSetInterface::contains_range()
+            #[inline]
+            pub fn contains_range(&self, range: core::ops::RangeInclusive<usize>) -> bool {
+                $crate::internals::contains_range(self, range)
+            }
+            
+            /// See [BitSetInterface::count_in_range()]
+            /// 
+            /// [BitSetInterface::count_in_range()]: crate::BitSetInterface::count_in_range()
+            #[inline]
+            pub fn count_in_range(&self, range: core::ops::RangeInclusive<usize>) -> usize {
+                $crate::internals::count_in_range(self, range)
+            }
+            
+            /// See [BitSetInterface::exact_len()]
+            /// 
+            /// [BitSetInterface::exact_len()]: crate::BitSetInterface::exact_len()
+            #[inline]
+            pub fn exact_len(&self) -> usize {
+                $crate::internals::exact_len(self)
+            }
+            
+            /// See [BitSetInterface::first()]
+            /// 
+            /// [BitSetInterface::first()]: crate::BitSetInterface::first()
+            #[inline]
+            pub fn first(&self) -> Option<usize> {
+                $crate::internals::first(self)
+            }
+            
+            /// See [BitSetInterface::last()]
+            /// 
+            /// [BitSetInterface::last()]: crate::BitSetInterface::last()
+            #[inline]
+            pub fn last(&self) -> Option<usize> {
+                $crate::internals::last(self)
+            }
+            
+            /// See [BitSetInterface::count_trailing_zeros()]
+            /// 
+            /// [BitSetInterface::count_trailing_zeros()]: crate::BitSetInterface::count_trailing_zeros()
+            #[inline]
+            pub fn count_trailing_zeros(&self) -> usize {
+                $crate::internals::count_trailing_zeros(self)
+            }
+            
+            /// See [BitSetInterface::count_leading_zeros()]
+            /// 
+            /// [BitSetInterface::count_leading_zeros()]: crate::BitSetInterface::count_leading_zeros()
+            #[inline]
+            pub fn count_leading_zeros(&self) -> usize {
+                $crate::internals::count_leading_zeros(self)
+            }
+            
+            /// See [BitSetInterface::first_unset()]
+            /// 
+            /// [BitSetInterface::first_unset()]: crate::BitSetInterface::first_unset()
+            #[inline]
+            pub fn first_unset<R>(&self, range: R) -> Option<usize>
+            where
+                R: core::ops::RangeBounds<usize>
+            {
+                $crate::internals::first_unset(self, range)
+            }
+            
+            /// See [BitSetInterface::is_contiguous()]
+            /// 
+            /// [BitSetInterface::is_contiguous()]: crate::BitSetInterface::is_contiguous()
+            #[inline]
+            pub fn is_contiguous(&self) -> bool {
+                $crate::internals::is_contiguous(self)
+            }
+            
+            /// See [BitSetInterface::to_range()]
+            /// 
+            /// [BitSetInterface::to_range()]: crate::BitSetInterface::to_range()
+            #[inline]
+            pub fn to_range(&self) -> Option<core::ops::RangeInclusive<usize>> {
+                $crate::internals::to_range(self)
+            }
+            
+            /// See [BitSetInterface::approximate_memory_usage()]
+            /// 
+            /// [BitSetInterface::approximate_memory_usage()]: crate::BitSetInterface::approximate_memory_usage()
+            #[inline]
+            pub fn approximate_memory_usage(&self) -> usize {
+                $crate::internals::LevelMasks::memory_usage_estimate(self)
+            }
+            
+            /// See [BitSetInterface::is_subset()]
+            /// 
+            /// [BitSetInterface::is_subset()]: crate::BitSetInterface::is_subset()
+            #[inline]
+            pub fn is_subset<Rhs>(&self, other: Rhs) -> bool
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::is_subset(self, other)
+            }
+            
+            /// See [BitSetInterface::is_superset()]
+            /// 
+            /// [BitSetInterface::is_superset()]: crate::BitSetInterface::is_superset()
+            #[inline]
+            pub fn is_superset<Rhs>(&self, other: Rhs) -> bool
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::is_subset(other, self)
+            }
+            
+            /// See [BitSetInterface::is_disjoint()]
+            /// 
+            /// [BitSetInterface::is_disjoint()]: crate::BitSetInterface::is_disjoint()
+            #[inline]
+            pub fn is_disjoint<Rhs>(&self, other: Rhs) -> bool
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::is_disjoint(self, other)
+            }
+            
+            /// See [BitSetInterface::intersection_size()]
+            /// 
+            /// [BitSetInterface::intersection_size()]: crate::BitSetInterface::intersection_size()
+            #[inline]
+            pub fn intersection_size<Rhs>(&self, other: Rhs) -> usize
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::apply_size($crate::ops::And, self, other)
+            }
+            
+            /// See [BitSetInterface::union_size()]
+            /// 
+            /// [BitSetInterface::union_size()]: crate::BitSetInterface::union_size()
+            #[inline]
+            pub fn union_size<Rhs>(&self, other: Rhs) -> usize
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::apply_size($crate::ops::Or, self, other)
+            }
+            
+            /// See [BitSetInterface::difference_size()]
+            /// 
+            /// [BitSetInterface::difference_size()]: crate::BitSetInterface::difference_size()
+            #[inline]
+            pub fn difference_size<Rhs>(&self, other: Rhs) -> usize
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::apply_size($crate::ops::Sub, self, other)
+            }
+            
+            /// See [BitSetInterface::symmetric_difference_size()]
+            /// 
+            /// [BitSetInterface::symmetric_difference_size()]: crate::BitSetInterface::symmetric_difference_size()
+            #[inline]
+            pub fn symmetric_difference_size<Rhs>(&self, other: Rhs) -> usize
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::apply_size($crate::ops::Xor, self, other)
+            }
+            
+            /// See [BitSetInterface::hamming_distance()]
+            /// 
+            /// [BitSetInterface::hamming_distance()]: crate::BitSetInterface::hamming_distance()
+            #[inline]
+            pub fn hamming_distance<Rhs>(&self, other: Rhs) -> usize
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::apply_size($crate::ops::Xor, self, other)
+            }
+            
+            /// See [BitSetInterface::jaccard_similarity()]
+            /// 
+            /// [BitSetInterface::jaccard_similarity()]: crate::BitSetInterface::jaccard_similarity()
+            #[inline]
+            pub fn jaccard_similarity<Rhs>(&self, other: Rhs) -> f64
+            where
+                Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>
+            {
+                $crate::internals::jaccard_similarity(self, other)
+            }
         }
         
         // --------------------------------
@@ -410,22 +993,75 @@ macro_rules! impl_bitset {
             $($where_bounds)*
         {}
         
+        // --------------------------------
+        // Ord
+        impl<$($generics),*,Rhs> PartialOrd<Rhs> for $t
+        where
+            Rhs: $crate::internals::LevelMasksIterExt<Conf = <Self as $crate::BitSetBase>::Conf>,
+            $($where_bounds)*
+        {
+            /// See [Ord] implementation.
+            #[inline]
+            fn partial_cmp(&self, other: &Rhs) -> Option<core::cmp::Ordering> {
+                Some($crate::internals::cmp(self, other))
+            }
+        }
+        
+        impl<$($generics),*> Ord for $t
+        where
+            $($where_bounds)*
+        {
+            /// Lexicographical comparison of index sequences, 
+            /// same as for [BTreeSet].
+            /// 
+            /// [BTreeSet]: std::collections::BTreeSet
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                $crate::internals::cmp(self, other)
+            }
+        }
+        
+        
+        // --------------------------------
+        // Hash
+        impl<$($generics),*> core::hash::Hash for $t
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                $crate::internals::hash(self, state)
+            }
+        }
         
         // --------------------------------
         // Debug
-        impl<$($generics),*> std::fmt::Debug for $t
+        impl<$($generics),*> core::fmt::Debug for $t
         where
             $($where_bounds)*
         {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                f.debug_list().entries(self.iter()).finish()
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::internals::debug_fmt(self, f)
+            }
+        }
+        
+        // --------------------------------
+        // Display
+        impl<$($generics),*> core::fmt::Display for $t
+        where
+            $($where_bounds)*
+        {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::internals::display_fmt(self, f)
             }
         }
         
         
         // ---------------------------------
         // And
-        impl<$($generics),*, Rhs> std::ops::BitAnd<Rhs> for &$t
+        impl<$($generics),*, Rhs> core::ops::BitAnd<Rhs> for &$t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
             $($where_bounds)*         
@@ -441,7 +1077,7 @@ macro_rules! impl_bitset {
         
         // ---------------------------------
         // Or
-        impl<$($generics),*, Rhs> std::ops::BitOr<Rhs> for &$t
+        impl<$($generics),*, Rhs> core::ops::BitOr<Rhs> for &$t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
             $($where_bounds)*         
@@ -457,7 +1093,7 @@ macro_rules! impl_bitset {
         
         // ---------------------------------
         // Xor
-        impl<$($generics),*, Rhs> std::ops::BitXor<Rhs> for &$t
+        impl<$($generics),*, Rhs> core::ops::BitXor<Rhs> for &$t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
             $($where_bounds)*         
@@ -473,7 +1109,7 @@ macro_rules! impl_bitset {
         
         // ---------------------------------
         // Sub
-        impl<$($generics),*, Rhs> std::ops::Sub<Rhs> for &$t
+        impl<$($generics),*, Rhs> core::ops::Sub<Rhs> for &$t
         where
             Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
             $($where_bounds)*         
@@ -488,6 +1124,37 @@ macro_rules! impl_bitset {
                 $crate::apply($crate::ops::Sub, self, rhs)
             }
         }
+        
+        // ---------------------------------
+        // Shr
+        impl<$($generics),*, Rhs> core::ops::Shr<Rhs> for &$t
+        where
+            Rhs: $crate::BitSetInterface<Conf = <Self as $crate::BitSetBase>::Conf>,
+            $($where_bounds)*         
+        {
+            type Output = $crate::Apply<$crate::ops::Implication, Self, Rhs>;
+
+            /// Returns implication `self -> rhs`, or `!self | rhs`.
+            #[inline]
+            fn shr(self, rhs: Rhs) -> Self::Output{
+                $crate::apply($crate::ops::Implication, self, rhs)
+            }
+        }
+        
+        // ---------------------------------
+        // Not
+        impl<$($generics),*> core::ops::Not for &$t
+        where
+            $($where_bounds)*         
+        {
+            type Output = $crate::ComplementBitSet<Self>;
+
+            /// Returns complement of self.
+            #[inline]
+            fn not(self) -> Self::Output{
+                $crate::ops::complement(self)
+            }
+        }
     };
 }
 pub(crate) use impl_bitset;
\ No newline at end of file
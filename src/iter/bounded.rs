@@ -0,0 +1,122 @@
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds};
+use crate::bitset_interface::LevelMasksIterExt;
+use crate::config::{Config, DefaultBlockIterator, DefaultIndexIterator};
+use crate::DataBlock;
+use crate::iter::IndexCursor;
+
+/// Block iterator, that stops after the block containing `last` index.
+/// 
+/// Returned blocks are not masked - the last one may contain 
+/// indices past `last`.
+/// 
+/// Constructed by [BitSetInterface::block_iter_until()].
+/// 
+/// [BitSetInterface::block_iter_until()]: crate::BitSetInterface::block_iter_until()
+pub struct BoundedBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    iter: DefaultBlockIterator<T>,
+    last: usize,
+}
+
+impl<T> BoundedBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    #[inline]
+    pub(crate) fn new(iter: DefaultBlockIterator<T>, last: usize) -> Self {
+        Self{ iter, last }
+    }
+}
+
+impl<T> Iterator for BoundedBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    type Item = DataBlock<<T::Conf as Config>::DataBitBlock>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Blocks are ascending - all next ones will be past `last` too.
+        self.iter.next().filter(|block| block.start_index <= self.last)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T> FusedIterator for BoundedBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{}
+
+/// Index iterator, that stops after `last` index.
+/// 
+/// Constructed by [BitSetInterface::iter_until()] and [BitSetInterface::iter_range()].
+/// 
+/// [BitSetInterface::iter_until()]: crate::BitSetInterface::iter_until()
+/// [BitSetInterface::iter_range()]: crate::BitSetInterface::iter_range()
+pub struct BoundedIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    iter: DefaultIndexIterator<T>,
+    last: usize,
+}
+
+impl<T> BoundedIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    #[inline]
+    pub(crate) fn new(iter: DefaultIndexIterator<T>, last: usize) -> Self {
+        Self{ iter, last }
+    }
+    
+    /// `iter` indices within `range`.
+    #[inline]
+    pub(crate) fn with_range<R: RangeBounds<usize>>(iter: DefaultIndexIterator<T>, range: R) -> Self {
+        let first = match range.start_bound() {
+            Bound::Included(&i) => Some(i),
+            Bound::Excluded(&i) => i.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let last = match range.end_bound() {
+            Bound::Included(&i) => Some(i),
+            Bound::Excluded(&i) => i.checked_sub(1),
+            Bound::Unbounded => Some(usize::MAX),
+        };
+        match (first, last) {
+            (Some(first), Some(last)) if first <= last => 
+                Self::new(iter.move_to(IndexCursor::from(first)), last),
+            _ => Self::new(iter.move_to(IndexCursor::end()), 0),
+        }
+    }
+}
+
+impl<T> Iterator for BoundedIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Indices are ascending - all next ones will be past `last` too.
+        self.iter.next().filter(|&index| index <= self.last)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T> FusedIterator for BoundedIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{}
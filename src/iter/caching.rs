@@ -1,7 +1,8 @@
-use std::marker::PhantomData;
-use std::mem;
-use std::mem::{ManuallyDrop, MaybeUninit};
-use std::ops::ControlFlow;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::ControlFlow;
 
 use crate::bit_block::BitBlock;
 use crate::bit_queue::BitQueue;
@@ -45,7 +46,7 @@ use crate::iter::{BlockCursor, IndexCursor};
 /// [reduce]: crate::reduce()
 /// [binary_op]: crate::ops
 /// [traverse]: Self::traverse
-/// [for_each]: std::iter::Iterator::for_each
+/// [for_each]: core::iter::Iterator::for_each
 pub struct CachingBlockIter<T>
 where
     T: LevelMasksIterExt,
@@ -81,7 +82,7 @@ where
         if !have_state {
             // bitwise-copy level1_block_data if have no IterState state.
             
-            this.level1_block_data = unsafe{ std::ptr::read(&self.level1_block_data) };
+            this.level1_block_data = unsafe{ core::ptr::read(&self.level1_block_data) };
         } else {
             // update level1_block_data otherwise.
             // (because level1_block_data may depends on state)
@@ -175,6 +176,23 @@ where
     #[must_use]
     #[inline]
     pub fn move_to(mut self, cursor: BlockCursor<T::Conf>) -> Self{
+        self.set_cursor(cursor);
+        self
+    }
+    
+    /// In-place version of [move_to], for block containing `index`.
+    /// 
+    /// If there is no such block - iterator moves to the next one.
+    /// Position can be moved backward as well. Fast O(1) operation.
+    /// 
+    /// [move_to]: Self::move_to
+    #[inline]
+    pub fn advance_to_block(&mut self, index: usize) {
+        self.set_cursor(BlockCursor::from(index));
+    }
+    
+    #[inline]
+    fn set_cursor(&mut self, cursor: BlockCursor<T::Conf>) {
         // Reset level0 mask if we not in "initial state"
         if self.level0_index != usize::MAX{
             self.level0_iter = self.virtual_set.level0_mask().into_bits_iter();    
@@ -209,13 +227,11 @@ where
             self.level1_iter  = BitQueue::empty();
             self.level0_index = <T::Conf as Config>::DataBitBlock::size(); 
         }
-
-        self
     }
 
     /// Stable [try_for_each] version.
     /// 
-    /// [try_for_each]: std::iter::Iterator::try_for_each
+    /// [try_for_each]: core::iter::Iterator::try_for_each
     #[inline]
     pub fn traverse<F>(mut self, mut f: F) -> ControlFlow<()>
     where
@@ -231,7 +247,7 @@ where
         if self.level0_index != usize::MAX{
             let level0_index = self.level0_index;
             
-            let level1_iter = unsafe{ std::ptr::read(&self.level1_iter) };
+            let level1_iter = unsafe{ core::ptr::read(&self.level1_iter) };
             let ctrl = level1_iter.traverse(
                 |level1_index| level1_mask_traverse_fn::<T, _>(
                     level0_index, level1_index, &self.level1_block_data, |b| f(b)
@@ -242,7 +258,7 @@ where
             }
         }
 
-        let level0_iter = unsafe{ std::ptr::read(&self.level0_iter) };
+        let level0_iter = unsafe{ core::ptr::read(&self.level0_iter) };
         level0_iter.traverse(
             |level0_index| level0_mask_traverse_fn(
                 &self.virtual_set,
@@ -303,6 +319,37 @@ where
         Some(DataBlock { start_index: block_start_index, bit_block: data_mask })
     }
 
+    /// Each raised level1 bit yields one block. In [TRUSTED_HIERARCHY],
+    /// each raised level0 bit yields at least one block.
+    /// 
+    /// Exact, when iterator reached the last level1 block.
+    /// 
+    /// [TRUSTED_HIERARCHY]: crate::BitSetBase::TRUSTED_HIERARCHY
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (level1_len, _) = self.level1_iter.size_hint();
+        let (level0_len, _) = self.level0_iter.size_hint();
+        let min = if T::TRUSTED_HIERARCHY {
+            level1_len + level0_len
+        } else {
+            level1_len
+        };
+        let max = level1_len + level0_len * <T::Conf as Config>::Level1BitBlock::size();
+        (min, Some(max))
+    }
+
+    /// Sum of level1 masks popcounts. Does not touch data blocks.
+    #[inline]
+    fn count(mut self) -> usize {
+        let level1_iter = mem::replace(&mut self.level1_iter, BitQueue::empty());
+        let mut len = level1_iter.count();
+        for level0_index in &mut self.level0_iter {
+            let level1_mask = unsafe{ self.virtual_set.level1_mask(level0_index) };
+            len += level1_mask.count_ones();
+        }
+        len
+    }
+
     #[inline]
     fn for_each<F>(self, mut f: F)
     where
@@ -315,6 +362,11 @@ where
     }
 }
 
+impl<T> FusedIterator for CachingBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{}
+
 impl<T> Drop for CachingBlockIter<T>
 where
     T: LevelMasksIterExt
@@ -341,7 +393,7 @@ where
 ///
 /// [BitSetInterface]: crate::BitSetInterface
 /// [traverse]: Self::traverse
-/// [for_each]: std::iter::Iterator::for_each
+/// [for_each]: core::iter::Iterator::for_each
 pub struct CachingIndexIter<T>
 where
     T: LevelMasksIterExt,
@@ -386,7 +438,24 @@ where
     #[must_use]
     #[inline]
     pub fn move_to(mut self, cursor: IndexCursor<T::Conf>) -> Self {
-        self.block_iter = self.block_iter.move_to(cursor.block_cursor);
+        self.set_cursor(cursor);
+        self
+    }
+    
+    /// In-place version of [move_to], for `index`.
+    /// 
+    /// If `index` is not in the set - iterator moves to the next one.
+    /// Position can be moved backward as well. Fast O(1) operation.
+    /// 
+    /// [move_to]: Self::move_to
+    #[inline]
+    pub fn advance_to(&mut self, index: usize) {
+        self.set_cursor(IndexCursor::from(index));
+    }
+    
+    #[inline]
+    fn set_cursor(&mut self, cursor: IndexCursor<T::Conf>) {
+        self.block_iter.set_cursor(cursor.block_cursor);
         
         self.data_block_iter = 
         if let Some(data_block) = self.block_iter.next(){
@@ -409,9 +478,7 @@ where
                 start_index: usize::MAX,
                 bit_block_iter: BitQueue::empty(),
             }
-        };       
-
-        self 
+        };
     }    
 
     /// Same as [CachingBlockIter::cursor], but for index.
@@ -435,9 +502,34 @@ where
         }        
     }
 
+    /// Returns next index, without consuming it.
+    /// 
+    /// Unlike [Peekable], does not buffer anything - iterator just 
+    /// advances to the next non-empty data block. Cursor, [move_to] and [traverse]
+    /// work as usual.
+    /// 
+    /// [Peekable]: core::iter::Peekable
+    /// [move_to]: Self::move_to
+    /// [traverse]: Self::traverse
+    #[inline]
+    pub fn peek(&mut self) -> Option<usize> {
+        loop{
+            // BitQueue is just a copy of bit block.
+            if let Some(index) = self.data_block_iter.clone().next(){
+                return Some(index);
+            }
+
+            if let Some(data_block) = self.block_iter.next(){
+                self.data_block_iter = data_block.into_iter();
+            } else {
+                return None;
+            }
+        }
+    }
+
     /// Stable [try_for_each] version.
     /// 
-    /// [try_for_each]: std::iter::Iterator::try_for_each
+    /// [try_for_each]: core::iter::Iterator::try_for_each
     #[inline]
     pub fn traverse<F>(mut self, mut f: F) -> ControlFlow<()>
     where
@@ -455,7 +547,7 @@ where
             }
 
             // 2. traverse rest of the level1 block
-            let level1_iter = unsafe{ std::ptr::read(&self.block_iter.level1_iter) };
+            let level1_iter = unsafe{ core::ptr::read(&self.block_iter.level1_iter) };
             let ctrl = level1_iter.traverse(
                 |level1_index| level1_mask_traverse_fn::<T, _>(
                     level0_index, level1_index, &self.block_iter.level1_block_data,
@@ -467,7 +559,7 @@ where
             }
         }
 
-        let level0_iter = unsafe{ std::ptr::read(&self.block_iter.level0_iter) };
+        let level0_iter = unsafe{ core::ptr::read(&self.block_iter.level0_iter) };
         level0_iter.traverse(
             |level0_index| level0_mask_traverse_fn(
                 &self.block_iter.virtual_set,
@@ -502,6 +594,57 @@ where
         }
     }
 
+    /// Exact only when iterator reached the last data block. 
+    /// Upper bound assumes all remaining data blocks are full.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (data_block_len, _) = self.data_block_iter.size_hint();
+        // In trusted hierarchy each block have at least one index.
+        let (blocks_min, blocks_max) = self.block_iter.size_hint();
+        let min = if T::TRUSTED_HIERARCHY {
+            data_block_len + blocks_min
+        } else {
+            data_block_len
+        };
+        let max = blocks_max.and_then(|blocks_max| 
+            blocks_max
+                .checked_mul(<T::Conf as Config>::DataBitBlock::size())?
+                .checked_add(data_block_len)
+        );
+        (min, max)
+    }
+
+    /// Sum of data blocks popcounts. Does not iterate indices.
+    #[inline]
+    fn count(self) -> usize {
+        let mut len = self.data_block_iter.count();
+        let _ = self.block_iter.traverse(|block|{
+            len += block.count_ones();
+            ControlFlow::Continue(())
+        });
+        len
+    }
+
+    /// Skips whole data blocks, using their popcounts.
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        loop{
+            let (len, _) = self.data_block_iter.size_hint();
+            if n < len {
+                return self.data_block_iter.nth(n);
+            }
+            n -= len;
+
+            if let Some(data_block) = self.block_iter.next(){
+                self.data_block_iter = data_block.into_iter();
+            } else {
+                // consume the rest
+                self.data_block_iter.nth(len);
+                return None;
+            }
+        }
+    }
+
     #[inline]
     fn for_each<F>(self, mut f: F)
     where
@@ -514,6 +657,11 @@ where
     }    
 }
 
+impl<T> FusedIterator for CachingIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{}
+
 
 #[inline]
 fn level1_mask_traverse_fn<S, F>(
@@ -0,0 +1,99 @@
+use core::iter::FusedIterator;
+use core::ops::ControlFlow;
+use crate::bit_block::BitBlock;
+use crate::bitset_interface::LevelMasksIterExt;
+use crate::config::{Config, DefaultBlockIterator};
+use crate::{BitSet, DataBlock};
+
+/// Iterator of [BitSet]s, each with the next `chunk_size` elements.
+///
+/// The last chunk may have less elements. Data blocks, that fit
+/// into chunk as a whole, are copied without per-index processing.
+///
+/// Constructed by [BitSetInterface::chunks()].
+///
+/// [BitSetInterface::chunks()]: crate::BitSetInterface::chunks()
+pub struct ChunksIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    block_iter: DefaultBlockIterator<T>,
+    /// Not yet consumed part of data block, split by previous chunk.
+    pending: Option<DataBlock<<T::Conf as Config>::DataBitBlock>>,
+    chunk_size: usize,
+}
+
+impl<T> ChunksIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    #[inline]
+    pub(crate) fn new(virtual_set: T, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        Self{
+            block_iter: DefaultBlockIterator::new(virtual_set),
+            pending: None,
+            chunk_size,
+        }
+    }
+}
+
+impl<T> Iterator for ChunksIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    type Item = BitSet<T::Conf>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = BitSet::default();
+        let mut remaining = self.chunk_size;
+        while remaining != 0 {
+            let Some(block) = self.pending.take().or_else(|| self.block_iter.next()) else {
+                break;
+            };
+            let len = block.count_ones();
+            if len == 0 {
+                continue;
+            }
+            if len <= remaining {
+                remaining -= len;
+                chunk.insert_datablock(block);
+                continue;
+            }
+
+            // Split block: first `remaining` bits go to chunk.
+            let mut head = <T::Conf as Config>::DataBitBlock::zero();
+            let mut n = 0;
+            let _ = block.bit_block.traverse_bits(|index|{
+                head.set_bit::<true>(index);
+                n += 1;
+                if n == remaining {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+            self.pending = Some(DataBlock{
+                start_index: block.start_index,
+                bit_block: block.bit_block ^ head,
+            });
+            chunk.insert_datablock(DataBlock{
+                start_index: block.start_index,
+                bit_block: head,
+            });
+            remaining = 0;
+        }
+
+        if remaining == self.chunk_size {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+impl<T> FusedIterator for ChunksIter<T>
+where
+    T: LevelMasksIterExt,
+{}
@@ -0,0 +1,73 @@
+use core::iter::FusedIterator;
+use crate::bitset_interface::LevelMasksIterExt;
+use crate::config::DefaultIndexIterator;
+
+/// Index iterator with known length.
+/// 
+/// Length is computed once, at construction - as sum of data blocks
+/// popcounts. Which is O(data blocks), instead of O(indices).
+/// 
+/// Constructed by [BitSetInterface::exact_iter()].
+/// 
+/// [BitSetInterface::exact_iter()]: crate::BitSetInterface::exact_iter()
+pub struct ExactIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    iter: DefaultIndexIterator<T>,
+    len: usize,
+}
+
+impl<T> ExactIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    /// `len` must be exact number of indices in `virtual_set`.
+    #[inline]
+    pub(crate) fn new(virtual_set: T, len: usize) -> Self {
+        Self{
+            iter: DefaultIndexIterator::new(virtual_set),
+            len
+        }
+    }
+}
+
+impl<T> Iterator for ExactIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.iter.next()?;
+        self.len -= 1;
+        Some(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.len = self.len.saturating_sub(n).saturating_sub(1);
+        self.iter.nth(n)
+    }
+}
+
+impl<T> ExactSizeIterator for ExactIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{}
+
+impl<T> FusedIterator for ExactIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{}
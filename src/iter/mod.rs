@@ -1,19 +1,51 @@
 //! Iteration always return ordered (or sorted) index sequences.
 
-use std::marker::PhantomData;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
 
-use crate::{DataBlock, level_indices};
+use crate::{Apply, BitSetBase, BitSetInterface, ComplementBitSet, data_block_start_index, DataBlock, level_indices, RangeBitSet};
+use crate::ops::And;
 use crate::bit_block::BitBlock;
 use crate::config::{Config, max_addressable_index};
 
 mod caching;
 pub use caching::{CachingBlockIter, CachingIndexIter};
 
-#[cfg(feature = "simple_iter")]
+mod bounded;
+pub use bounded::{BoundedBlockIter, BoundedIndexIter};
+
+mod chunks;
+pub use chunks::ChunksIter;
+
+mod exact;
+pub use exact::ExactIndexIter;
+
+/// Iterator of [BitSetInterface::iter_complement_bounded()].
+/// 
+/// [BitSetInterface::iter_complement_bounded()]: crate::BitSetInterface::iter_complement_bounded()
+pub type ComplementBoundedIter<T> = CachingIndexIter<
+    Apply<And, ComplementBitSet<T>, RangeBitSet<<T as BitSetBase>::Conf>>
+>;
+
+mod ranges;
+pub use ranges::RangesIter;
+
+mod rev;
+pub use rev::{RevBlockIter, RevIndexIter};
+
+mod slices;
+pub use slices::BlockSlicesIter;
+
 mod simple;
-#[cfg(feature = "simple_iter")]
 pub use simple::{SimpleBlockIter, SimpleIndexIter};
 
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use par::{ParBlockIter, ParIndexIter};
+
 /// Block iterator cursor, or position of iterable.
 /// 
 /// Created by [CachingBlockIter::cursor()], used by [CachingBlockIter::move_to()].
@@ -58,7 +90,7 @@ impl<Conf: Config> BlockCursor<Conf>{
     /// Constructs cursor that points to the start of bitset.
     #[inline]
     pub fn start() -> Self{
-        unsafe{ std::mem::zeroed() }
+        unsafe{ core::mem::zeroed() }
     }
     
     /// Constructs cursor that points to the end of bitset.
@@ -73,13 +105,112 @@ impl<Conf: Config> BlockCursor<Conf>{
             level1_next_index: Conf::Level1BitBlock::size() as u16,
             phantom: Default::default(),
         }
-    }   
+    }
+    
+    /// Does this cursor point to position before `other`?
+    #[inline]
+    pub fn is_before(&self, other: &Self) -> bool {
+        self < other
+    }
+    
+    /// Are cursor indices within `Conf` hierarchy?
+    /// 
+    /// Always true for cursors constructed in-process. Checked on deserialization
+    /// (with `serde` feature) - where cursor could be saved with different `Conf`.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.fits::<Conf>()
+    }
+    
+    /// Are cursor indices within `S` hierarchy?
+    /// 
+    /// Same as [is_valid()], but for `S::Conf`, which may differ from cursor `Conf`.
+    /// 
+    /// [is_valid()]: Self::is_valid
+    #[inline]
+    pub fn validate_for<S: BitSetInterface>(&self) -> bool {
+        self.fits::<S::Conf>()
+    }
+    
+    #[inline]
+    fn fits<C: Config>(&self) -> bool {
+        self.level0_index as usize <= C::Level0BitBlock::size()
+        && self.level1_next_index as usize <= C::Level1BitBlock::size()
+    }
+    
+    /// Cursor, structurally halfway between `a` and `b`. O(1).
+    /// 
+    /// This is a midpoint in hierarchy positions - not in elements count.
+    /// For sparse or non-uniform bitsets, halves may have very different 
+    /// element count. Still, good enough for splitting work between threads.
+    /// See [element_count_between()] for exact count.
+    /// 
+    /// [element_count_between()]: Self::element_count_between
+    #[inline]
+    pub fn midpoint(a: Self, b: Self) -> Self {
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+        if a == b {
+            return a;
+        }
+        let level1_size = Conf::Level1BitBlock::size();
+        let a_pos = a.level0_index as usize * level1_size + a.level1_next_index as usize;
+        let b_pos = b.level0_index as usize * level1_size + b.level1_next_index as usize;
+        let pos = a_pos + (b_pos - a_pos) / 2;
+        Self{
+            level0_index: (pos / level1_size) as u16,
+            level1_next_index: (pos % level1_size) as u16,
+            phantom: PhantomData
+        }
+    }
+    
+    /// Number of `bitset` elements in blocks from `a` (inclusive) to `b` (exclusive).
+    /// 
+    /// O(N) of blocks in between - each block popcount is summed.
+    #[inline]
+    pub fn element_count_between<S>(bitset: S, a: Self, b: Self) -> usize
+    where
+        S: BitSetInterface<Conf = Conf>
+    {
+        let mut count = 0;
+        let _ = CachingBlockIter::new(bitset).move_to(a).traverse(|block|{
+            if BlockCursor::from(&block) >= b {
+                return ControlFlow::Break(());
+            }
+            count += block.count_ones();
+            ControlFlow::Continue(())
+        });
+        count
+    }
+}
+
+impl<Conf: Config> PartialEq for BlockCursor<Conf>{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+impl<Conf: Config> Eq for BlockCursor<Conf>{}
+
+impl<Conf: Config> PartialOrd for BlockCursor<Conf>{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Conf: Config> Ord for BlockCursor<Conf>{
+    /// Cursors ordered by position they point to.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.level0_index, self.level1_next_index)
+            .cmp(&(other.level0_index, other.level1_next_index))
+    }
 }
 
 impl<Conf: Config> Clone for BlockCursor<Conf>{
     #[inline]
     fn clone(&self) -> Self {
-        unsafe{ std::ptr::read(self) }
+        unsafe{ core::ptr::read(self) }
     }
 }
 impl<Conf: Config> Copy for BlockCursor<Conf>{}
@@ -90,7 +221,7 @@ impl<Conf: Config> From<usize> for BlockCursor<Conf>{
     fn from(mut index: usize) -> Self {
         // It is ok to use max_addressable_index instead of max_value,
         // because we point past the actual bitset data anyway.
-        index = std::cmp::min(index, max_addressable_index::<Conf>());
+        index = core::cmp::min(index, max_addressable_index::<Conf>());
 
         let (level0, level1, _) = level_indices::<Conf>(index);
         Self{
@@ -134,7 +265,7 @@ impl<Conf: Config> IndexCursor<Conf>{
     /// Constructs cursor that points to the start of the bitset.
     #[inline]
     pub fn start() -> Self{
-        unsafe{ std::mem::zeroed() }
+        unsafe{ core::mem::zeroed() }
     }
     
     /// Constructs cursor that points to the end of the bitset.
@@ -148,13 +279,105 @@ impl<Conf: Config> IndexCursor<Conf>{
             block_cursor: BlockCursor::end(),
             data_next_index: Conf::DataBitBlock::size() as u32
         }
-    }   
+    }
+    
+    /// Does this cursor point to position before `other`?
+    #[inline]
+    pub fn is_before(&self, other: &Self) -> bool {
+        self < other
+    }
+    
+    /// Same as [BlockCursor::is_valid()], but for index.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.fits::<Conf>()
+    }
+    
+    /// Same as [BlockCursor::validate_for()], but for index.
+    #[inline]
+    pub fn validate_for<S: BitSetInterface>(&self) -> bool {
+        self.fits::<S::Conf>()
+    }
+    
+    #[inline]
+    fn fits<C: Config>(&self) -> bool {
+        self.block_cursor.fits::<C>()
+        && self.data_next_index as usize <= C::DataBitBlock::size()
+    }
+    
+    /// Index this cursor points to.
+    #[inline]
+    fn index(&self) -> usize {
+        data_block_start_index::<Conf>(
+            self.block_cursor.level0_index as usize, 
+            self.block_cursor.level1_next_index as usize
+        ) + self.data_next_index as usize
+    }
+    
+    /// Cursor, that points `n` indices further. 
+    /// 
+    /// Moving past the bitset range results in cursor pointing to the end.
+    #[must_use]
+    #[inline]
+    pub fn advance_by(&self, n: usize) -> Self {
+        Self::from(self.index().saturating_add(n))
+    }
+    
+    /// Cursor, that points `n` `bitset` elements back.
+    /// 
+    /// Iterator [moved to] resulting cursor will replay last `n` elements 
+    /// before `self`. If there are less than `n` elements before `self` - 
+    /// cursor points to the start.
+    /// 
+    /// Elements before `self` are iterated in reverse, with hierarchy 
+    /// limited by [RangeBitSet] - O(n) + O(N) of empty blocks in between.
+    /// 
+    /// [moved to]: CachingIndexIter::move_to
+    #[must_use]
+    #[inline]
+    pub fn rewind_by<S>(&self, bitset: S, n: usize) -> Self
+    where
+        S: BitSetInterface<Conf = Conf>
+    {
+        if n == 0 {
+            return *self;
+        }
+        let before = Apply::new(And, bitset, RangeBitSet::<Conf>::new(..self.index()));
+        match RevIndexIter::new(before).nth(n - 1) {
+            Some(index) => Self::from(index),
+            None => Self::start(),
+        }
+    }
+}
+
+impl<Conf: Config> PartialEq for IndexCursor<Conf>{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+impl<Conf: Config> Eq for IndexCursor<Conf>{}
+
+impl<Conf: Config> PartialOrd for IndexCursor<Conf>{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Conf: Config> Ord for IndexCursor<Conf>{
+    /// Cursors ordered by position they point to.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.block_cursor.cmp(&other.block_cursor)
+            .then(self.data_next_index.cmp(&other.data_next_index))
+    }
 }
 
 impl<Conf: Config> Clone for IndexCursor<Conf>{
     #[inline]
     fn clone(&self) -> Self {
-        unsafe{ std::ptr::read(self) }
+        unsafe{ core::ptr::read(self) }
     }
 }
 impl<Conf: Config> Copy for IndexCursor<Conf>{}
@@ -163,7 +386,7 @@ impl<Conf: Config> From<usize> for IndexCursor<Conf>{
     /// Build cursor that points to the `index`.
     #[inline]
     fn from(mut index: usize) -> Self {
-        index = std::cmp::min(index, max_addressable_index::<Conf>());
+        index = core::cmp::min(index, max_addressable_index::<Conf>());
 
         let (level0, level1, data) = level_indices::<Conf>(index);
         Self{
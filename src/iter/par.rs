@@ -0,0 +1,220 @@
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use rayon::iter::ParallelIterator;
+use rayon::iter::plumbing::{bridge_unindexed, Folder, UnindexedConsumer, UnindexedProducer};
+use crate::bit_block::BitBlock;
+use crate::bitset_interface::LevelMasksIterExt;
+use crate::config::Config;
+use crate::DataBlock;
+use super::{BlockCursor, CachingBlockIter};
+
+/// Parallel [CachingBlockIter].
+///
+/// Splits work by level0 ranges - each rayon job iterates its own range
+/// of level0 blocks with its own [CachingBlockIter].
+///
+/// Since the number of data blocks is not known without traversal,
+/// this is [ParallelIterator] only (not [IndexedParallelIterator]).
+///
+/// Constructed by [BitSetInterface::par_block_iter()].
+///
+/// [IndexedParallelIterator]: rayon::iter::IndexedParallelIterator
+/// [BitSetInterface::par_block_iter()]: crate::BitSetInterface::par_block_iter()
+pub struct ParBlockIter<T>{
+    virtual_set: T,
+}
+
+impl<T> ParBlockIter<T>{
+    #[inline]
+    pub(crate) fn new(virtual_set: T) -> Self {
+        Self{ virtual_set }
+    }
+}
+
+impl<T> ParallelIterator for ParBlockIter<T>
+where
+    T: LevelMasksIterExt + Clone + Send,
+    <T::Conf as Config>::DataBitBlock: Send
+{
+    type Item = DataBlock<<T::Conf as Config>::DataBitBlock>;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let producer = Producer{
+            level0_begin: 0,
+            level0_end: <T::Conf as Config>::Level0BitBlock::size(),
+            virtual_set: self.virtual_set,
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+/// Parallel [CachingIndexIter].
+///
+/// [ParBlockIter], with each data block flattened to indices.
+///
+/// Constructed by [BitSetInterface::par_iter()].
+///
+/// [CachingIndexIter]: super::CachingIndexIter
+/// [BitSetInterface::par_iter()]: crate::BitSetInterface::par_iter()
+pub struct ParIndexIter<T>{
+    block_iter: ParBlockIter<T>
+}
+
+impl<T> ParIndexIter<T>{
+    #[inline]
+    pub(crate) fn new(virtual_set: T) -> Self {
+        Self{ block_iter: ParBlockIter::new(virtual_set) }
+    }
+}
+
+impl<T> ParallelIterator for ParIndexIter<T>
+where
+    T: LevelMasksIterExt + Clone + Send,
+    <T::Conf as Config>::DataBitBlock: Send
+{
+    type Item = usize;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        self.block_iter
+            .flat_map_iter(|block| block.into_iter())
+            .drive_unindexed(consumer)
+    }
+}
+
+struct Producer<T>{
+    /// Level0 indices range of this job.
+    level0_begin: usize,
+    level0_end: usize,
+    virtual_set: T,
+}
+
+impl<T> UnindexedProducer for Producer<T>
+where
+    T: LevelMasksIterExt + Clone + Send,
+    <T::Conf as Config>::DataBitBlock: Send
+{
+    type Item = DataBlock<<T::Conf as Config>::DataBitBlock>;
+
+    /// Split at the middle raised level0 bit of the range.
+    fn split(self) -> (Self, Option<Self>) {
+        let level0_mask = self.virtual_set.level0_mask();
+        let mut len = 0;
+        let _ = level0_mask.traverse_bits(|index|{
+            if index >= self.level0_end {
+                return ControlFlow::Break(());
+            }
+            if index >= self.level0_begin {
+                len += 1;
+            }
+            ControlFlow::Continue(())
+        });
+        if len < 2 {
+            return (self, None);
+        }
+
+        let mut mid = 0;
+        let mut n = len / 2;
+        let _ = level0_mask.traverse_bits(|index|{
+            if index >= self.level0_begin {
+                if n == 0 {
+                    mid = index;
+                    return ControlFlow::Break(());
+                }
+                n -= 1;
+            }
+            ControlFlow::Continue(())
+        });
+
+        let right = Self{
+            level0_begin: mid,
+            level0_end: self.level0_end,
+            virtual_set: self.virtual_set.clone(),
+        };
+        let left = Self{
+            level0_begin: self.level0_begin,
+            level0_end: mid,
+            virtual_set: self.virtual_set,
+        };
+        (left, Some(right))
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+    where
+        F: Folder<Self::Item>
+    {
+        let cursor = BlockCursor::<T::Conf>{
+            level0_index: self.level0_begin as u16,
+            level1_next_index: 0,
+            phantom: PhantomData,
+        };
+        let level0_end = self.level0_end;
+        let level1_block_capacity_pot_exp =
+            <T::Conf as Config>::Level1BitBlock::SIZE_POT_EXPONENT
+            + <T::Conf as Config>::DataBitBlock::SIZE_POT_EXPONENT;
+
+        let mut folder = Some(folder);
+        let _ = CachingBlockIter::new(self.virtual_set)
+            .move_to(cursor)
+            .traverse(|block|{
+                let level0_index = block.start_index >> level1_block_capacity_pot_exp;
+                if level0_index >= level0_end {
+                    return ControlFlow::Break(());
+                }
+
+                let f = unsafe{ folder.take().unwrap_unchecked() }.consume(block);
+                let full = f.full();
+                folder = Some(f);
+                if full {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+        unsafe{ folder.unwrap_unchecked() }
+    }
+}
+
+#[cfg(test)]
+mod test{
+    use rayon::iter::ParallelIterator;
+    use itertools::assert_equal;
+    use crate::{apply, BitSet, BitSetInterface, SmallBitSet};
+    use crate::config::_128bit;
+    use crate::ops::And;
+
+    #[test]
+    fn par_iter_test(){
+        let mut set1: BitSet<_128bit> = Default::default();
+        let mut set2: SmallBitSet<_128bit> = Default::default();
+        for i in (0..200_000).step_by(7){
+            set1.insert(i);
+        }
+        for i in (0..200_000).step_by(5){
+            set2.insert(i);
+        }
+
+        let mut par: Vec<usize> = set1.par_iter().collect();
+        par.sort();
+        assert_equal(par, set1.iter());
+
+        let and = apply(And, &set1, &set2);
+        let mut par: Vec<usize> = and.par_iter().collect();
+        par.sort();
+        assert_equal(par, and.iter());
+
+        let mut par: Vec<_> = and.par_block_iter().map(|block| block.start_index).collect();
+        par.sort();
+        assert_equal(par, and.block_iter().map(|block| block.start_index));
+
+        let empty: BitSet<_128bit> = Default::default();
+        assert_eq!(empty.par_iter().count(), 0);
+    }
+}
@@ -0,0 +1,190 @@
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+use crate::bit_block::BitBlock;
+use crate::bitset_interface::LevelMasksIterExt;
+use crate::config::{Config, DefaultBlockIterator};
+use crate::DataBlock;
+
+/// Iterator of maximal contiguous ranges of set indices.
+///
+/// Ranges are returned in ascending order, and never touch each other -
+/// adjacent runs of neighbour data blocks are merged.
+///
+/// Constructed by [BitSetInterface::iter_ranges()].
+///
+/// [BitSetInterface::iter_ranges()]: crate::BitSetInterface::iter_ranges()
+pub struct RangesIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    block_iter: DefaultBlockIterator<T>,
+    /// Not yet consumed part of current data block.
+    data_block: DataBlock<<T::Conf as Config>::DataBitBlock>,
+    /// Range, that may be continued by the next run.
+    pending: Option<(usize, usize)>,
+}
+
+impl<T> RangesIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    #[inline]
+    pub(crate) fn new(virtual_set: T) -> Self {
+        Self{
+            block_iter: DefaultBlockIterator::new(virtual_set),
+            data_block: DataBlock{
+                start_index: 0,
+                bit_block: BitBlock::zero()
+            },
+            pending: None,
+        }
+    }
+
+    /// Next run of ones in `data_block`. Found run is zeroed out.
+    #[inline]
+    fn next_block_run(&mut self) -> Option<(usize, usize)> {
+        let words = self.data_block.bit_block.as_array_mut();
+
+        let mut word_index = words.iter().position(|w| *w != 0)?;
+        let first_bit = unsafe{ words.get_unchecked(word_index) }.trailing_zeros() as usize;
+        let first = word_index * 64 + first_bit;
+
+        // Find run end, possibly spanning over multiple words.
+        let mut bit = first_bit;
+        let last = loop {
+            let word = unsafe{ words.get_unchecked_mut(word_index) };
+            let run_len = (!(*word >> bit)).trailing_zeros() as usize;
+            if run_len == 0 {
+                // previous word ended the run
+                break word_index * 64 - 1;
+            }
+            let run_end = bit + run_len;
+
+            // zero out run bits
+            if run_end == 64 {
+                *word &= !(u64::MAX << bit);
+            } else {
+                *word &= !((u64::MAX >> (64 - run_len)) << bit);
+            }
+
+            if run_end < 64 || word_index + 1 == words.len() {
+                break word_index * 64 + run_end - 1;
+            }
+            word_index += 1;
+            bit = 0;
+        };
+
+        let start_index = self.data_block.start_index;
+        Some((start_index + first, start_index + last))
+    }
+}
+
+impl<T> Iterator for RangesIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    type Item = RangeInclusive<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let run = if let Some(run) = self.next_block_run() {
+                run
+            } else if let Some(data_block) = self.block_iter.next() {
+                self.data_block = data_block;
+                continue;
+            } else {
+                return self.pending.take().map(|(first, last)| first..=last);
+            };
+
+            match &mut self.pending {
+                Some((_, last)) if *last + 1 == run.0 => {
+                    *last = run.1;
+                }
+                pending => {
+                    if let Some((first, last)) = pending.replace(run) {
+                        return Some(first..=last);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for RangesIter<T>
+where
+    T: LevelMasksIterExt,
+{}
+
+#[cfg(test)]
+mod test{
+    use itertools::assert_equal;
+    use crate::{BitSet, BitSetInterface};
+    use crate::config::_64bit;
+
+    #[test]
+    fn ranges_test(){
+        let mut set: BitSet<_64bit> = Default::default();
+        assert_eq!(set.iter_ranges().next(), None);
+
+        let ranges = [0..=0, 2..=5, 63..=64, 100..=4200, 5000..=5000, 10_000..=20_000];
+        for range in ranges.clone(){
+            for i in range{
+                set.insert(i);
+            }
+        }
+        assert_equal(set.iter_ranges(), ranges.clone());
+
+        let mut set2: BitSet<_64bit> = Default::default();
+        for range in set.iter_ranges(){
+            for i in range{
+                set2.insert(i);
+            }
+        }
+        assert_eq!(set, set2);
+
+        // Full data blocks.
+        let mut set: BitSet<_64bit> = Default::default();
+        for i in 0..64*64*2 {
+            set.insert(i);
+        }
+        assert_equal(set.iter_ranges(), [0..=64*64*2-1]);
+    }
+
+    #[test]
+    fn from_ranges_test(){
+        let ranges = [0..=0, 2..=5, 63..=64, 100..=4200, 5000..=5000, 10_000..=20_000];
+        let set = BitSet::<_64bit>::from_ranges(ranges.clone());
+        assert_equal(set.iter_ranges(), ranges.clone());
+        assert_eq!(BitSet::from_ranges(set.iter_ranges()), set);
+
+        // Overlapping, touching, unordered and empty ranges.
+        #[allow(clippy::reversed_empty_ranges)]
+        let set = BitSet::<_64bit>::from_ranges([300..=400, 10..=20, 21..=30, 350..=500, 7..=6]);
+        assert_equal(set.iter_ranges(), [10..=30, 300..=500]);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn ranges_multiword_test(){
+        use crate::config::_256bit;
+        use crate::{apply, ops::And};
+
+        let mut set: BitSet<_256bit> = Default::default();
+        let ranges = [3..=3, 60..=70, 127..=128, 191..=191, 200..=600, 767..=768, 1000..=1023];
+        for range in ranges.clone(){
+            for i in range{
+                set.insert(i);
+            }
+        }
+        assert_equal(set.iter_ranges(), ranges.clone());
+
+        // Non-trusted hierarchy
+        let mut other: BitSet<_256bit> = Default::default();
+        for i in 64..=700{
+            other.insert(i);
+        }
+        let and = apply(And, &set, &other);
+        assert_equal(and.iter_ranges(), [64..=70, 127..=128, 191..=191, 200..=600]);
+    }
+}
@@ -0,0 +1,180 @@
+use core::iter::FusedIterator;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use crate::bit_block::BitBlock;
+use crate::bitset_interface::{BitSetBase, LevelMasksIterExt};
+use crate::config::Config;
+use crate::{data_block_start_index, DataBlock};
+
+/// Removes and returns the highest raised bit.
+#[inline]
+fn pop_last_bit<B: BitBlock>(block: &mut B) -> Option<usize> {
+    for (i, word) in block.as_array_mut().iter_mut().enumerate().rev(){
+        if *word != 0 {
+            let bit = 63 - word.leading_zeros() as usize;
+            *word ^= 1 << bit;
+            return Some(i * 64 + bit);
+        }
+    }
+    None
+}
+
+/// Reverse block iterator.
+/// 
+/// Same as [CachingBlockIter], but iterates from the last block to the first.
+/// 
+/// Constructed by [BitSetInterface::rev_block_iter()].
+/// 
+/// [CachingBlockIter]: super::CachingBlockIter
+/// [BitSetInterface::rev_block_iter()]: crate::BitSetInterface::rev_block_iter()
+pub struct RevBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    virtual_set: T,
+
+    /// Not yet visited level0 bits.
+    level0_mask: <T::Conf as Config>::Level0BitBlock,
+    /// Not yet visited level1 bits of current level1 block.
+    level1_mask: <T::Conf as Config>::Level1BitBlock,
+    level0_index: usize,
+
+    state: ManuallyDrop<T::IterState>,
+    level1_block_data: MaybeUninit<T::Level1BlockData>,
+}
+
+impl<T> RevBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    #[inline]
+    pub(crate) fn new(virtual_set: T) -> Self {
+        let level0_mask = virtual_set.level0_mask();
+        let state = virtual_set.make_iter_state();
+        Self{
+            virtual_set,
+            level0_mask,
+            level1_mask: BitBlock::zero(),
+            level0_index: 0,
+            state: ManuallyDrop::new(state),
+            level1_block_data: MaybeUninit::new(Default::default())
+        }
+    }
+    
+    /// Into reverse index iterator.
+    #[inline]
+    pub fn into_indices(self) -> RevIndexIter<T> {
+        RevIndexIter{
+            block_iter: self,
+            data_block: DataBlock{ start_index: 0, bit_block: BitBlock::zero() },
+        }
+    }
+}
+
+impl<T> Iterator for RevBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    type Item = DataBlock<<T::Conf as Config>::DataBitBlock>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let level1_index = loop {
+            if let Some(index) = pop_last_bit(&mut self.level1_mask) {
+                break index;
+            }
+            
+            let level0_index = pop_last_bit(&mut self.level0_mask)?;
+            self.level0_index = level0_index;
+            self.level1_mask = unsafe {
+                self.level1_block_data.assume_init_drop();
+                let (level1_mask, _) =
+                    self.virtual_set.init_level1_block_data(
+                        &mut self.state,
+                        &mut self.level1_block_data,
+                        level0_index
+                    );
+                level1_mask
+            };
+        };
+
+        let data_mask = unsafe {
+            T::data_mask_from_block_data(
+                self.level1_block_data.assume_init_ref(), level1_index
+            )
+        };
+
+        let block_start_index =
+            data_block_start_index::<<T as BitSetBase>::Conf>(
+                self.level0_index, level1_index,
+            );
+
+        Some(DataBlock { start_index: block_start_index, bit_block: data_mask })
+    }
+}
+
+impl<T> FusedIterator for RevBlockIter<T>
+where
+    T: LevelMasksIterExt,
+{}
+
+impl<T> Drop for RevBlockIter<T>
+where
+    T: LevelMasksIterExt
+{
+    #[inline]
+    fn drop(&mut self) {
+        unsafe{
+            self.level1_block_data.assume_init_drop();
+            self.virtual_set.drop_iter_state(&mut self.state);
+        }
+    }
+}
+
+/// Reverse index iterator.
+/// 
+/// Iterates indices in descending order.
+/// 
+/// Constructed by [BitSetInterface::rev_iter()].
+/// 
+/// [BitSetInterface::rev_iter()]: crate::BitSetInterface::rev_iter()
+pub struct RevIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    block_iter: RevBlockIter<T>,
+    /// Not yet visited bits of current data block.
+    data_block: DataBlock<<T::Conf as Config>::DataBitBlock>,
+}
+
+impl<T> RevIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    #[inline]
+    pub(crate) fn new(virtual_set: T) -> Self {
+        RevBlockIter::new(virtual_set).into_indices()
+    }
+}
+
+impl<T> Iterator for RevIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // looping, because BlockIter may return empty DataBlocks.
+        loop{
+            if let Some(index) = pop_last_bit(&mut self.data_block.bit_block){
+                return Some(self.data_block.start_index + index);
+            }
+            self.data_block = self.block_iter.next()?;
+        }
+    }
+}
+
+impl<T> FusedIterator for RevIndexIter<T>
+where
+    T: LevelMasksIterExt,
+{}
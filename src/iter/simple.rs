@@ -1,3 +1,4 @@
+use core::iter::FusedIterator;
 use crate::bitset_interface::{BitSetBase, LevelMasks};
 use crate::bit_queue::BitQueue;
 use crate::{BitBlock, data_block_start_index, DataBlock, DataBlockIter};
@@ -12,6 +13,12 @@ use crate::config::Config;
 ///
 /// May have similar to [CachingBlockIter] performance on very sparse sets.
 /// 
+/// Works with any `LevelMasks` - no need to implement `LevelMasksIterExt`.
+/// This is the simplest way to iterate custom bitset:
+/// `SimpleIndexIter::new(SimpleBlockIter::new(set))`. 
+/// Otherwise prefer [CachingBlockIter] - it is faster.
+/// 
+/// [CachingBlockIter]: super::CachingBlockIter
 /// [cache::NoCache]: crate::cache::NoCache
 pub struct SimpleBlockIter<T>
 where
@@ -81,6 +88,16 @@ where
     }
 }
 
+impl<T> FusedIterator for SimpleBlockIter<T>
+where
+    T: LevelMasks,
+{}
+
+/// Index iterator over [SimpleBlockIter].
+/// 
+/// Same as [CachingIndexIter], but works with any `LevelMasks`.
+/// 
+/// [CachingIndexIter]: super::CachingIndexIter
 // It's just flatmap across block iterator.
 pub struct SimpleIndexIter<T>
 where
@@ -126,4 +143,9 @@ where
             }
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T> FusedIterator for SimpleIndexIter<T>
+where
+    T: LevelMasks
+{}
\ No newline at end of file
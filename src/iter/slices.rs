@@ -0,0 +1,51 @@
+use core::iter::FusedIterator;
+use crate::bitset_interface::LevelMasksIterExt;
+use crate::config::DefaultBlockIterator;
+
+/// Iterator of non-empty data blocks, as `(start_index, &[u64])`.
+///
+/// Words are borrowed from bitset storage - no copy.
+///
+/// Constructed by `BitSet::iter_blocks_as_slices()`, `SmallBitSet::iter_blocks_as_slices()`.
+pub struct BlockSlicesIter<'a, T>
+where
+    &'a T: LevelMasksIterExt,
+{
+    bitset: &'a T,
+    block_iter: DefaultBlockIterator<&'a T>,
+    /// Data block words, by data block start index.
+    block_words: fn(&'a T, usize) -> &'a [u64],
+}
+
+impl<'a, T> BlockSlicesIter<'a, T>
+where
+    &'a T: LevelMasksIterExt,
+{
+    /// `block_words` will be called only for existing data blocks.
+    #[inline]
+    pub(crate) fn new(bitset: &'a T, block_words: fn(&'a T, usize) -> &'a [u64]) -> Self {
+        Self{
+            bitset,
+            block_iter: DefaultBlockIterator::new(bitset),
+            block_words
+        }
+    }
+}
+
+impl<'a, T> Iterator for BlockSlicesIter<'a, T>
+where
+    &'a T: LevelMasksIterExt,
+{
+    type Item = (usize, &'a [u64]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let block = self.block_iter.next()?;
+        Some((block.start_index, (self.block_words)(self.bitset, block.start_index)))
+    }
+}
+
+impl<'a, T> FusedIterator for BlockSlicesIter<'a, T>
+where
+    &'a T: LevelMasksIterExt,
+{}
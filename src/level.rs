@@ -1,3 +1,5 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use crate::BitBlock;
 use crate::primitive::Primitive;
 
@@ -44,6 +46,12 @@ pub trait IBlock: Sized + Default{
     fn is_empty(&self) -> bool {
         Self::Mask::is_zero(self.mask())
     }
+    
+    /// Heap memory, owned by block. Block itself not included.
+    #[inline]
+    fn heap_memory_usage(&self) -> usize {
+        0
+    }
 }
 
 #[derive(Clone)]
@@ -68,6 +76,53 @@ impl<Block: IBlock> Default for Level<Block> {
 }
 
 impl<Block: IBlock> Level<Block> {
+    /// Have space for `capacity` blocks, without reallocation.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut blocks = Vec::with_capacity(capacity + 1);
+        //Always have empty block at index 0.
+        blocks.push(Default::default());
+        Self{
+            blocks,
+            root_empty_block: u64::MAX,
+        }
+    }
+    
+    /// Removes all blocks, except empty one at index 0. 
+    /// Keeps allocated memory.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.blocks.truncate(1);
+        self.root_empty_block = u64::MAX;
+    }
+
+    /// Appends `block` at the end, bypassing empty blocks list.
+    /// 
+    /// Returns `block` index.
+    #[inline]
+    pub fn push_block(&mut self, block: Block) -> usize {
+        let index = self.blocks.len();
+        self.blocks.push(block);
+        index
+    }
+
+    /// Blocks count, including empty ones.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+    
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.blocks.capacity()
+    }
+    
+    /// Sum of [IBlock::heap_memory_usage()] of all blocks.
+    #[inline]
+    pub fn blocks_heap_memory_usage(&self) -> usize {
+        self.blocks.iter().map(IBlock::heap_memory_usage).sum()
+    }
+
     #[inline]
     pub fn blocks(&self) -> &[Block] {
         self.blocks.as_slice()
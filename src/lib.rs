@@ -1,5 +1,6 @@
 #![cfg_attr(miri, feature(alloc_layout_extra) )]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 //! Hierarchical sparse bitset. 
 //! 
 //! Memory consumption does not depend on max index inserted.
@@ -88,10 +89,10 @@
 //! 
 //! You can define your own inter-bitset operation, by implementing [BitSetOp].
 //! 
-//! [&]: std::ops::BitAnd
-//! [|]: std::ops::BitOr
-//! [`^`]: std::ops::BitXor
-//! [-]: std::ops::Sub
+//! [&]: core::ops::BitAnd
+//! [|]: core::ops::BitOr
+//! [`^`]: core::ops::BitXor
+//! [-]: core::ops::Sub
 //! 
 //! # Cursor
 //! 
@@ -109,8 +110,8 @@
 //! [BitSetInterface] iterators have [for_each] specialization and stable [try_for_each] version - [traverse].
 //! For tight loops, traversing is observably faster then iterating.
 //! 
-//! [for_each]: std::iter::Iterator::for_each
-//! [try_for_each]: std::iter::Iterator::try_for_each
+//! [for_each]: core::iter::Iterator::for_each
+//! [try_for_each]: core::iter::Iterator::try_for_each
 //! [traverse]: crate::iter::CachingIndexIter::traverse
 //! 
 //! # TrustedHierarchy
@@ -133,6 +134,56 @@
 //! You can iterate [DataBlock]s instead of individual indices. DataBlocks can be moved, cloned
 //! and iterated for indices.
 //! 
+//! # Serde
+//! 
+//! With `serde` feature, [BitSet] and [SmallBitSet] implement `Serialize` and `Deserialize`.
+//! Bitset is serialized as a sequence of its non-empty data blocks. Each data block 
+//! is a tuple of `start_index` and bitblock's `u64`s.
+//! Wrap bitset into [IndexListFormat] to (de)serialize it as a plain sorted list
+//! of indices, for interop with other languages.
+//! 
+//! # Rayon
+//! 
+//! With `rayon` feature, all bitsets can be iterated in parallel with
+//! [BitSetInterface::par_iter()] and [BitSetInterface::par_block_iter()].
+//! Work is split by level0 blocks. Parallel iteration is unordered.
+//! 
+//! # Binary format
+//! 
+//! With `bincode` feature, [BitSet] and [SmallBitSet] have `serialize_bincode()`/`deserialize_bincode()`.
+//! Format follows bitset hierarchy: level0 mask, then level1 mask for each
+//! raised level0 bit, then data block for each raised level1 bit. 
+//! All as little-endian `u64`s. This is crate's own format - no `bincode` 
+//! crate dependency.
+//! 
+//! Same format, prefixed with header (magic, version and `Config` level sizes) is
+//! used by [BitSet::write_to()]/[BitSet::read_from()] - which need `std` feature.
+//! They write/read block by block, without holding whole encoded bitset in memory.
+//! 
+//! # Roaring
+//! 
+//! With `roaring` feature, [BitSet] and [SmallBitSet] can be converted from/to 
+//! [RoaringBitmap](https://docs.rs/roaring/latest/roaring/bitmap/struct.RoaringBitmap.html).
+//! 
+//! # FixedBitSet
+//! 
+//! With `fixedbitset` feature, [BitSet] and [SmallBitSet] can be converted from/to 
+//! [FixedBitSet](https://docs.rs/fixedbitset/latest/fixedbitset/struct.FixedBitSet.html).
+//! Conversion copies data blocks word by word.
+//! 
+//! # Proptest
+//! 
+//! With `proptest` feature, [BitSet] and [SmallBitSet] implement 
+//! [Arbitrary](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html).
+//! Generated bitsets are parameterized with [ArbitraryParams]:
+//! max index, density and max elements count.
+//! 
+//! # no_std
+//! 
+//! Disable default `std` feature to use library with `alloc` only.
+//! Without `std`, `write_to()`/`read_from()` and `rayon`/`roaring`/`proptest`
+//! integrations are not available. `simd`, `serde`, `fixedbitset` and `bincode` work as is.
+//! 
 //! # Custom bitsets
 //! 
 //! You can make your own bitsets - like 
@@ -147,8 +198,8 @@
 //! 
 //! ## SIMD
 //! 
-//! 128 and 256 bit configurations use SIMD, powered by [wide]. Make sure you compile with simd support
-//! enabled (on x86: `sse2` for _128bit, `avx` for _256bit) to achieve best performance.
+//! 128, 256 and 512 bit configurations use SIMD, powered by [wide]. Make sure you compile with simd support
+//! enabled (on x86: `sse2` for _128bit, `avx` for _256bit and _512bit) to achieve best performance.
 //! _sse2 enabled by default in Rust for most desktop environments_ 
 //!
 //! If you want to use other SIMD types/registers - see [internals] module.
@@ -156,9 +207,12 @@
 //!
 //! [wide]: https://crates.io/crates/wide
 
+extern crate alloc;
+
 #[cfg(test)]
 mod test;
 
+mod macros;
 mod primitive;
 mod primitive_array;
 mod block;
@@ -170,10 +224,22 @@ mod bit_utils;
 mod reduce;
 mod bitset_interface;
 mod apply;
+mod apply3;
+mod complement;
+mod empty;
+mod full;
+mod offset;
+mod range;
 mod raw;
 mod derive_raw;
 mod bitset;
 mod small_bitset;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(any(feature = "std", feature = "bincode"))]
+mod binary;
+#[cfg(feature = "proptest")]
+mod proptest;
 
 pub mod config;
 pub mod ops;
@@ -183,14 +249,38 @@ pub mod internals;
 
 pub use bitset_interface::{BitSetBase, BitSetInterface};
 pub use apply::Apply;
+pub use apply3::Apply3;
+pub use complement::ComplementBitSet;
+pub use empty::EmptyBitSet;
+pub use full::FullBitSet;
+pub use offset::OffsetBitSet;
+pub use range::RangeBitSet;
 pub use reduce::Reduce;
 pub use bit_block::BitBlock;
+#[cfg(feature = "simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
+pub use bit_block::U64x8;
 pub use bitset::BitSet;
 pub use small_bitset::SmallBitSet;
+pub use raw::{LevelStats, MemoryUsage};
+#[cfg(feature = "bincode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+pub use binary::BinaryDecodeError;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use crate::serde::IndexListFormat;
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub use crate::proptest::ArbitraryParams;
 
 use primitive::Primitive;
 use primitive_array::PrimitiveArray;
-use std::ops::ControlFlow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::ControlFlow;
+use alloc::vec;
+use alloc::vec::Vec;
 use config::Config;
 use ops::BitSetOp;
 use bit_queue::BitQueue;
@@ -199,7 +289,7 @@ use cache::ReduceCache;
 macro_rules! assume {
     ($e: expr) => {
         if !($e){
-            std::hint::unreachable_unchecked();
+            core::hint::unreachable_unchecked();
         }
     };
 }
@@ -274,10 +364,119 @@ impl<Block: BitBlock> DataBlock<Block>{
         self.bit_block.count_ones()
     }
     
+    /// Same as [len()].
+    /// 
+    /// [len()]: Self::len
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.bit_block.count_ones()
+    }
+    
+    /// Number of unset bits in DataBlock.
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        Block::size() - self.bit_block.count_ones()
+    }
+    
+    /// Index of the first set bit. Same as `iter().next()`, but without iterator.
+    /// 
+    /// Returned index is absolute - `start_index` included.
+    #[inline]
+    pub fn first_index(&self) -> Option<usize> {
+        let words = self.bit_block.as_array();
+        let (i, word) = words.iter().enumerate().find(|(_, w)| **w != 0)?;
+        Some(self.start_index + i*64 + word.trailing_zeros() as usize)
+    }
+    
+    /// Index of the last set bit.
+    /// 
+    /// Returned index is absolute - `start_index` included.
+    #[inline]
+    pub fn last_index(&self) -> Option<usize> {
+        let words = self.bit_block.as_array();
+        let (i, word) = words.iter().enumerate().rfind(|(_, w)| **w != 0)?;
+        Some(self.start_index + i*64 + 63 - word.leading_zeros() as usize)
+    }
+    
     #[inline]
     pub fn is_empty(&self) -> bool {
         self.bit_block.is_zero()
     }
+    
+    /// Elements of both blocks.
+    /// 
+    /// Blocks must have the same `start_index` - this is checked only in debug builds.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.start_index, other.start_index);
+        Self{ start_index: self.start_index, bit_block: self.bit_block & other.bit_block }
+    }
+    
+    /// Elements of either block.
+    /// 
+    /// Blocks must have the same `start_index` - this is checked only in debug builds.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.start_index, other.start_index);
+        Self{ start_index: self.start_index, bit_block: self.bit_block | other.bit_block }
+    }
+    
+    /// Elements of `self`, not in `other`.
+    /// 
+    /// Blocks must have the same `start_index` - this is checked only in debug builds.
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.start_index, other.start_index);
+        let bit_block = self.bit_block & (self.bit_block ^ other.bit_block);
+        Self{ start_index: self.start_index, bit_block }
+    }
+    
+    /// Elements of exactly one block.
+    /// 
+    /// Blocks must have the same `start_index` - this is checked only in debug builds.
+    #[inline]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.start_index, other.start_index);
+        Self{ start_index: self.start_index, bit_block: self.bit_block ^ other.bit_block }
+    }
+    
+    /// Are all elements of `self` in `other`?
+    /// 
+    /// Blocks must have the same `start_index` - this is checked only in debug builds.
+    #[inline]
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+    
+    /// Same bits, with `start_index` set to `new_start`.
+    /// 
+    /// To be inserted into bitset, `new_start` must be aligned to block size.
+    #[inline]
+    pub fn shift_start(&self, new_start: usize) -> Self {
+        Self{ start_index: new_start, bit_block: self.bit_block }
+    }
+}
+impl<Block: BitBlock> Hash for DataBlock<Block>{
+    /// Hashes `start_index` and `bit_block` words.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start_index.hash(state);
+        self.bit_block.as_array().hash(state);
+    }
+}
+impl<Block: BitBlock> PartialOrd for DataBlock<Block>{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<Block: BitBlock> Ord for DataBlock<Block>{
+    /// By `start_index`, then by `bit_block` words.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.start_index.cmp(&other.start_index)
+            .then_with(|| self.bit_block.as_array().cmp(other.bit_block.as_array()))
+    }
 }
 impl<Block: BitBlock> IntoIterator for DataBlock<Block>{
     type Item = usize;
@@ -303,7 +502,7 @@ impl<Block: BitBlock> DataBlockIter<Block>{
     /// 
     /// traverse approx. 15% faster then iterator
     /// 
-    /// [try_for_each]: std::iter::Iterator::try_for_each
+    /// [try_for_each]: core::iter::Iterator::try_for_each
     #[inline]
     pub fn traverse<F>(self, mut f: F) -> ControlFlow<()>
     where
@@ -320,6 +519,21 @@ impl<Block: BitBlock> Iterator for DataBlockIter<Block>{
         self.bit_block_iter.next().map(|index|self.start_index + index)
     }
 
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bit_block_iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.bit_block_iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.bit_block_iter.nth(n).map(|index|self.start_index + index)
+    }
+
     #[inline]
     fn for_each<F>(self, mut f: F)
     where
@@ -332,6 +546,8 @@ impl<Block: BitBlock> Iterator for DataBlockIter<Block>{
     }
 }
 
+impl<Block: BitBlock> FusedIterator for DataBlockIter<Block>{}
+
 /// Creates a lazy bitset, as [BitSetOp] application between two bitsets.
 #[inline]
 pub fn apply<Op, S1, S2>(op: Op, s1: S1, s2: S2) -> Apply<Op, S1, S2>
@@ -343,6 +559,24 @@ where
     Apply::new(op, s1, s2)
 }
 
+/// Creates a lazy bitset, as [Associative] operation application between 
+/// three bitsets.
+/// 
+/// Same as `apply(op, apply(op, s1, s2), s3)`, without intermediate lazy layer.
+/// For non-associative operations - nest [apply()]s.
+/// 
+/// [Associative]: ops::Associative
+#[inline]
+pub fn apply3<Op, S1, S2, S3>(op: Op, s1: S1, s2: S2, s3: S3) -> Apply3<Op, S1, S2, S3>
+where
+    Op: ops::Associative,
+    S1: BitSetInterface,
+    S2: BitSetInterface<Conf = <S1 as BitSetBase>::Conf>,
+    S3: BitSetInterface<Conf = <S1 as BitSetBase>::Conf>,
+{
+    Apply3::new(op, s1, s2, s3)
+}
+
 /// Creates a lazy bitset, as bitsets iterator reduction.
 ///
 /// "Reduce" term used in Rust's [Iterator::reduce] sense.
@@ -405,4 +639,210 @@ where
     Some(reduce::Reduce{ sets: bitsets, phantom: Default::default() })
 }
 
+/// Elements count of [reduce] result, without materializing it.
+/// 
+/// Sums data blocks popcounts - does not iterate individual indices.
+/// Returns 0, if the `bitsets` is empty.
+///
+/// # Safety
+///
+/// Panics, if [Config::DefaultCache] capacity is smaller then sets len.
+/// 
+/// [reduce]: reduce()
+#[inline]
+pub fn reduce_count<Conf, Op, I>(op: Op, bitsets: I) -> usize
+where
+    Conf: Config,
+    Op: BitSetOp,
+    I: Iterator + Clone,
+    I::Item: BitSetInterface<Conf = Conf>,
+{
+    let Some(reduced) = reduce(op, bitsets) else {
+        return 0;
+    };
+    let mut len = 0;
+    reduced.block_iter().for_each(|block| len += block.count_ones());
+    len
+}
+
+/// Folds [reduce] result indices into `state`, without materializing it.
+///
+/// `f(&mut state, index)` is called for each element of reduction, in
+/// ascending order. Iterates with [traverse] - O(result size).
+/// Returns `state` as is, if the `bitsets` is empty.
+///
+/// ```
+/// # use hi_sparse_bitset::{reduce_w_state, BitSet, config::_64bit};
+/// # use hi_sparse_bitset::ops::And;
+/// let sets: [BitSet<_64bit>; 2] = [[1, 2, 3].into(), [2, 3, 4].into()];
+/// let sum = reduce_w_state(And, sets.iter(), 0, |sum, index| *sum += index);
+/// assert_eq!(sum, 5);
+/// ```
+///
+/// # Safety
+///
+/// Panics, if [Config::DefaultCache] capacity is smaller then sets len.
+///
+/// [reduce]: reduce()
+/// [traverse]: iter::CachingIndexIter::traverse
+#[inline]
+pub fn reduce_w_state<Conf, Op, I, S, F>(op: Op, bitsets: I, mut state: S, mut f: F) -> S
+where
+    Conf: Config,
+    Op: BitSetOp,
+    I: Iterator + Clone,
+    I::Item: BitSetInterface<Conf = Conf>,
+    F: FnMut(&mut S, usize),
+{
+    if let Some(reduced) = reduce(op, bitsets) {
+        reduced.iter().for_each(|index| f(&mut state, index));
+    }
+    state
+}
+
+/// Does [reduce] result have any elements?
+/// 
+/// Same as `!is_empty()` of lazy [reduce] result. For [TRUSTED_HIERARCHY]
+/// results (like [Or] of [BitSet]s) - only level0 mask checked. Otherwise -
+/// stops at the first non-empty data block.
+/// Returns false, if the `bitsets` is empty.
+///
+/// # Safety
+///
+/// Panics, if [Config::DefaultCache] capacity is smaller then sets len.
+/// 
+/// [reduce]: reduce()
+/// [TRUSTED_HIERARCHY]: BitSetBase::TRUSTED_HIERARCHY
+/// [Or]: ops::Or
+#[inline]
+pub fn reduce_any<Conf, Op, I>(op: Op, bitsets: I) -> bool
+where
+    Conf: Config,
+    Op: BitSetOp,
+    I: Iterator + Clone,
+    I::Item: BitSetInterface<Conf = Conf>,
+{
+    match reduce(op, bitsets) {
+        Some(reduced) => !reduced.is_empty(),
+        None => false,
+    }
+}
+
+/// First (minimal) element of [reduce] result.
+/// 
+/// Descends hierarchy with [BitSetInterface::first()] - no iterator 
+/// (and its cache) constructed.
+/// Returns None, if the `bitsets` is empty.
+///
+/// # Safety
+///
+/// Panics, if [Config::DefaultCache] capacity is smaller then sets len.
+/// 
+/// [reduce]: reduce()
+#[inline]
+pub fn reduce_first<Conf, Op, I>(op: Op, bitsets: I) -> Option<usize>
+where
+    Conf: Config,
+    Op: BitSetOp,
+    I: Iterator + Clone,
+    I::Item: BitSetInterface<Conf = Conf>,
+{
+    reduce(op, bitsets)?.first()
+}
+
+/// Writes [apply] result into `target`, reusing its memory.
+/// 
+/// `target` is cleared, then result data blocks are inserted as a whole.
+/// 
+/// [apply]: apply()
+#[inline]
+pub fn apply_into<Conf, Op, S1, S2>(target: &mut BitSet<Conf>, op: Op, s1: S1, s2: S2)
+where
+    Conf: Config,
+    Op: BitSetOp,
+    S1: BitSetInterface<Conf = Conf>,
+    S2: BitSetInterface<Conf = Conf>,
+{
+    target.clear();
+    *target |= apply(op, s1, s2);
+}
+
+/// Writes [reduce] result into `target`, reusing its memory.
+/// 
+/// `target` is cleared, then result data blocks are inserted as a whole.
+/// If the `bitsets` is empty - `target` is left empty.
+///
+/// # Safety
+///
+/// Panics, if [Config::DefaultCache] capacity is smaller then sets len.
+/// 
+/// [reduce]: reduce()
+#[inline]
+pub fn reduce_into<Conf, Op, I>(target: &mut BitSet<Conf>, op: Op, bitsets: I)
+where
+    Conf: Config,
+    Op: BitSetOp,
+    I: Iterator + Clone,
+    I::Item: BitSetInterface<Conf = Conf>,
+{
+    target.clear();
+    if let Some(reduced) = reduce(op, bitsets) {
+        *target |= reduced;
+    }
+}
+
+/// Indices, present in at least `k` of `bitsets`.
+/// 
+/// Data blocks of all `bitsets` are visited in lockstep. For each block, 
+/// "present in at least j sets" masks are accumulated bitwise, for each `j` 
+/// up to `k` - O(data blocks * `k` * sets len).
+/// 
+/// `k == 1` is union, `k == bitsets.len()` is intersection. 
+/// Returns empty bitset, if `k` is greater than `bitsets` len.
+/// 
+/// # Safety
+/// 
+/// Panics, if `k` is 0.
+pub fn reduce_threshold<Conf, I>(k: usize, bitsets: I) -> BitSet<Conf>
+where
+    Conf: Config,
+    I: IntoIterator,
+    I::Item: BitSetInterface<Conf = Conf>,
+{
+    assert!(k > 0, "k must be positive");
+    let mut block_iters: Vec<_> = bitsets.into_iter()
+        .map(|set| set.into_block_iter().peekable())
+        .collect();
+    
+    let mut result = BitSet::default();
+    if k > block_iters.len() {
+        return result;
+    }
+    
+    // at_least[j] - bits, present in at least j+1 sets.
+    let mut at_least = vec![Conf::DataBitBlock::zero(); k];
+    loop {
+        let start_index = block_iters.iter_mut()
+            .filter_map(|iter| iter.peek().map(|block| block.start_index))
+            .min();
+        let Some(start_index) = start_index else {
+            break;
+        };
+        
+        at_least.fill(Conf::DataBitBlock::zero());
+        for iter in &mut block_iters {
+            let Some(block) = iter.next_if(|block| block.start_index == start_index) else {
+                continue;
+            };
+            for j in (1..k).rev() {
+                at_least[j] = at_least[j] | (at_least[j-1] & block.bit_block);
+            }
+            at_least[0] = at_least[0] | block.bit_block;
+        }
+        
+        result.insert_datablock(DataBlock{ start_index, bit_block: at_least[k-1] });
+    }
+    result
+}
+
 // TODO: Do we need fold as well?
\ No newline at end of file
@@ -0,0 +1,48 @@
+/// [BitSet] literal.
+/// 
+/// ```
+/// # use hi_sparse_bitset::{bitset, config};
+/// let a = bitset![1, 3, 5, 7, 9];
+/// let b = bitset![0..100];
+/// let c = bitset![config::_64bit; 1, 2, 3];
+/// let d = bitset![config::_64bit; 0..=10];
+/// # assert!(a.contains(9) && b.contains(99) && c.contains(3) && d.contains(10));
+/// ```
+/// 
+/// `Conf` defaults to `_64bit`, regardless of enabled features.
+/// Range form accepts only single-token bounds: literals, identifiers or
+/// parenthesized expressions - `bitset![0..(n+1)]`.
+/// 
+/// Expands to `BitSet::from_iter()`.
+/// 
+/// [BitSet]: crate::BitSet
+#[macro_export]
+macro_rules! bitset {
+    () => {
+        $crate::BitSet::<$crate::config::_64bit>::new()
+    };
+    
+    (@ $conf:ty; $start:tt .. $end:tt) => {
+        <$crate::BitSet<$conf> as ::core::iter::FromIterator<usize>>::from_iter($start..$end)
+    };
+    (@ $conf:ty; $start:tt ..= $end:tt) => {
+        <$crate::BitSet<$conf> as ::core::iter::FromIterator<usize>>::from_iter($start..=$end)
+    };
+    (@ $conf:ty; $($index:expr),* $(,)?) => {
+        <$crate::BitSet<$conf> as ::core::iter::FromIterator<usize>>::from_iter([$($index),*])
+    };
+    
+    // Ranges go before `$conf:ty` - to not mistake parenthesized bound for a type.
+    ($start:tt .. $end:tt) => {
+        $crate::bitset!(@ $crate::config::_64bit; $start .. $end)
+    };
+    ($start:tt ..= $end:tt) => {
+        $crate::bitset!(@ $crate::config::_64bit; $start ..= $end)
+    };
+    ($conf:ty; $($rest:tt)*) => {
+        $crate::bitset!(@ $conf; $($rest)*)
+    };
+    ($($rest:tt)*) => {
+        $crate::bitset!(@ $crate::config::_64bit; $($rest)*)
+    };
+}
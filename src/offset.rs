@@ -0,0 +1,359 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr::addr_of_mut;
+use crate::BitSetInterface;
+use crate::bit_block::BitBlock;
+use crate::internals::impl_bitset;
+use crate::bitset_interface::{BitSetBase, LevelMasks, LevelMasksIterExt};
+use crate::config::{Config, max_addressable_index};
+use crate::range::ones_in_range;
+
+/// Bitset with all indices of `S` shifted up by constant offset, as lazy bitset.
+///
+/// Contains `i + offset` for each `i` in `S`. Indices, that end up outside
+/// of `S::Conf` hierarchy, are dropped. Like [ComplementBitSet], it can address
+/// indices past [max_capacity()].
+///
+/// Offset is decomposed into whole data blocks and in-block bit shift.
+/// Each resulting data block is stitched from (at most) two source data blocks,
+/// each resulting level1 block - from (at most) two source level1 blocks.
+///
+/// Useful for multi-segment indexing - each segment is a bitset with its own base:
+/// `apply(Or, OffsetBitSet::new(&segment0, 0), OffsetBitSet::new(&segment1, base1))`.
+///
+/// Hierarchy is not exact after shift, so this is not [TRUSTED_HIERARCHY].
+///
+/// [ComplementBitSet]: crate::ComplementBitSet
+/// [max_capacity()]: crate::config::max_capacity
+/// [TRUSTED_HIERARCHY]: BitSetBase::TRUSTED_HIERARCHY
+#[derive(Clone)]
+pub struct OffsetBitSet<S>{
+    s: S,
+    offset: usize,
+}
+
+impl<S: LevelMasks> OffsetBitSet<S>{
+    #[inline]
+    pub fn new(s: S, offset: usize) -> Self{
+        // Everything past hierarchy is dropped anyway.
+        let offset = core::cmp::min(offset, max_addressable_index::<S::Conf>());
+        Self{ s, offset }
+    }
+
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// (whole data blocks, in-block bit shift)
+    #[inline]
+    fn split_offset(&self) -> (usize, usize) {
+        let data_block_pot_exp = <S::Conf as Config>::DataBitBlock::SIZE_POT_EXPONENT;
+        (
+            self.offset >> data_block_pot_exp,
+            self.offset & ((1 << data_block_pot_exp) - 1)
+        )
+    }
+
+    /// Source level1 blocks, touched by `level0_index` block.
+    ///
+    /// Returns (first source level0 index, shift). Source data blocks
+    /// of resulting `level1_index` are `shift + level1_index` and
+    /// `shift + level1_index + 1`, counting from first source level1 block start.
+    /// First source level0 index can be -1.
+    #[inline]
+    fn source_level1_blocks(&self, level0_index: usize) -> (isize, usize) {
+        let level1_size = <S::Conf as Config>::Level1BitBlock::size();
+        let (offset_blocks, _) = self.split_offset();
+        let lo = (level0_index * level1_size) as isize - offset_blocks as isize - 1;
+        (
+            lo.div_euclid(level1_size as isize),
+            lo.rem_euclid(level1_size as isize) as usize
+        )
+    }
+}
+
+/// Bits `from..from+size` of `low` and `high` concatenation.
+///
+/// `from` is in `0..=size`.
+#[inline]
+fn window<B: BitBlock>(low: &B, high: &B, from: usize) -> B {
+    let low  = low.as_array();
+    let high = high.as_array();
+    let words_len = low.len();
+    let word = |i: usize| -> u64 {
+        if i < words_len {
+            low[i]
+        } else if i < words_len * 2 {
+            high[i - words_len]
+        } else {
+            0
+        }
+    };
+
+    let mut block = B::zero();
+    for (i, out) in block.as_array_mut().iter_mut().enumerate(){
+        let bit = from + i * 64;
+        let (word_index, bit_index) = (bit / 64, bit % 64);
+        *out = word(word_index) >> bit_index;
+        if bit_index != 0 {
+            *out |= word(word_index + 1) << (64 - bit_index);
+        }
+    }
+    block
+}
+
+impl<S: LevelMasks> BitSetBase for OffsetBitSet<S>{
+    type Conf = S::Conf;
+    const TRUSTED_HIERARCHY: bool = false;
+}
+
+impl<S: LevelMasks> LevelMasks for OffsetBitSet<S>{
+    #[inline]
+    fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
+        let level0_size = <Self::Conf as Config>::Level0BitBlock::size();
+        let level1_size = <Self::Conf as Config>::Level1BitBlock::size();
+        let (offset_blocks, offset_bits) = self.split_offset();
+
+        let mut mask = BitBlock::zero();
+        let _ = self.s.level0_mask().traverse_bits(|index|{
+            // Resulting data blocks of source level1 block.
+            let first = index * level1_size + offset_blocks;
+            let last  = first + level1_size - 1 + (offset_bits != 0) as usize;
+            let (first, last) = (first / level1_size, last / level1_size);
+            if first >= level0_size {
+                return core::ops::ControlFlow::Break(());
+            }
+            mask = mask | ones_in_range(first, core::cmp::min(last, level0_size - 1));
+            core::ops::ControlFlow::Continue(())
+        });
+        mask
+    }
+
+    #[inline]
+    unsafe fn level1_mask(&self, level0_index: usize)
+        -> <Self::Conf as Config>::Level1BitBlock
+    {
+        let level0_size = <Self::Conf as Config>::Level0BitBlock::size();
+        let (first, shift) = self.source_level1_blocks(level0_index);
+        let source_mask = |index: isize| {
+            if index >= 0 && (index as usize) < level0_size {
+                self.s.level1_mask(index as usize)
+            } else {
+                BitBlock::zero()
+            }
+        };
+        let masks = [source_mask(first), source_mask(first + 1)];
+        level1_mask_from_source::<Self::Conf>(&masks, shift, self.split_offset().1)
+    }
+
+    #[inline]
+    unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
+        -> <Self::Conf as Config>::DataBitBlock
+    {
+        let level1_size = <Self::Conf as Config>::Level1BitBlock::size();
+        let data_size   = <Self::Conf as Config>::DataBitBlock::size();
+        let (offset_blocks, offset_bits) = self.split_offset();
+
+        let block_index = level0_index * level1_size + level1_index;
+        let source_mask = |index: usize| {
+            self.s.data_mask(index / level1_size, index % level1_size)
+        };
+        let Some(high_index) = block_index.checked_sub(offset_blocks) else {
+            return BitBlock::zero();
+        };
+        let high = source_mask(high_index);
+        if offset_bits == 0 {
+            return high;
+        }
+        let low = match high_index.checked_sub(1) {
+            Some(low_index) => source_mask(low_index),
+            None => BitBlock::zero(),
+        };
+        window(&low, &high, data_size - offset_bits)
+    }
+
+    #[inline]
+    fn memory_usage_estimate(&self) -> usize {
+        self.s.memory_usage_estimate()
+    }
+}
+
+/// `masks` - two consecutive source level1 masks.
+#[inline]
+fn level1_mask_from_source<Conf: Config>(
+    masks: &[Conf::Level1BitBlock; 2], shift: usize, offset_bits: usize
+) -> Conf::Level1BitBlock {
+    // Source block `shift + 1 + i` is the high part of resulting block `i`.
+    let mut mask = window(&masks[0], &masks[1], shift + 1);
+    if offset_bits != 0 {
+        // Source block `shift + i` is the low part.
+        mask = mask | window(&masks[0], &masks[1], shift);
+    }
+    mask
+}
+
+impl<S: LevelMasksIterExt> LevelMasksIterExt for OffsetBitSet<S>{
+    /// (source level1 blocks data, is source level1 block in range, shift, offset_bits)
+    type Level1BlockData = ([S::Level1BlockData; 2], [bool; 2], usize, usize);
+
+    /// Each source level1 block needs its own state.
+    type IterState = (S::IterState, S::IterState);
+
+    #[inline]
+    fn make_iter_state(&self) -> Self::IterState {
+        (self.s.make_iter_state(), self.s.make_iter_state())
+    }
+
+    #[inline]
+    unsafe fn drop_iter_state(&self, state: &mut ManuallyDrop<Self::IterState>) {
+        self.s.drop_iter_state(&mut *addr_of_mut!(state.0).cast());
+        self.s.drop_iter_state(&mut *addr_of_mut!(state.1).cast());
+    }
+
+    #[inline]
+    unsafe fn init_level1_block_data(
+        &self,
+        state: &mut Self::IterState,
+        level1_block_data: &mut MaybeUninit<Self::Level1BlockData>,
+        level0_index: usize
+    ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
+        let level0_size = <Self::Conf as Config>::Level0BitBlock::size();
+        let (first, shift) = self.source_level1_blocks(level0_index);
+        let (_, offset_bits) = self.split_offset();
+
+        let ptr = level1_block_data.as_mut_ptr();
+        let blocks_data = &mut *addr_of_mut!((*ptr).0)
+            .cast::<[MaybeUninit<S::Level1BlockData>; 2]>();
+        let states = [&mut state.0, &mut state.1];
+
+        let mut masks = [BitBlock::zero(), BitBlock::zero()];
+        let mut in_range = [false, false];
+        let mut is_not_empty = false;
+        for (i, state) in states.into_iter().enumerate(){
+            let index = first + i as isize;
+            let block_data = &mut blocks_data[i];
+            if index < 0 || index as usize >= level0_size {
+                block_data.write(Default::default());
+                continue;
+            }
+            let (mask, v) = self.s.init_level1_block_data(
+                state, block_data, index as usize
+            );
+            masks[i] = mask;
+            in_range[i] = true;
+            is_not_empty |= v;
+        }
+
+        let mask = level1_mask_from_source::<Self::Conf>(&masks, shift, offset_bits);
+        addr_of_mut!((*ptr).1).write(in_range);
+        addr_of_mut!((*ptr).2).write(shift);
+        addr_of_mut!((*ptr).3).write(offset_bits);
+        (mask, is_not_empty)
+    }
+
+    #[inline]
+    unsafe fn data_mask_from_block_data(
+        level1_block_data: &Self::Level1BlockData, level1_index: usize
+    ) -> <Self::Conf as Config>::DataBitBlock {
+        let level1_size = <Self::Conf as Config>::Level1BitBlock::size();
+        let data_size   = <Self::Conf as Config>::DataBitBlock::size();
+        let (blocks_data, in_range, shift, offset_bits) = level1_block_data;
+
+        // `index` counts from the first source level1 block start.
+        let source_mask = |index: usize| {
+            let (i, level1_index) = (index / level1_size, index % level1_size);
+            if *in_range.get_unchecked(i) {
+                S::data_mask_from_block_data(blocks_data.get_unchecked(i), level1_index)
+            } else {
+                BitBlock::zero()
+            }
+        };
+        let index = shift + level1_index;
+        let high = source_mask(index + 1);
+        if *offset_bits == 0 {
+            return high;
+        }
+        let low = source_mask(index);
+        window(&low, &high, data_size - offset_bits)
+    }
+}
+
+impl_bitset!(
+    impl<S> for OffsetBitSet<S> where S: BitSetInterface
+);
+
+#[cfg(test)]
+mod test{
+    use itertools::assert_equal;
+    use rand::Rng;
+    use crate::{apply, BitSet, BitSetInterface};
+    use crate::bit_block::BitBlock;
+    use crate::config::{_64bit, Config, max_addressable_index};
+    use crate::ops::{And, Or};
+    use super::OffsetBitSet;
+
+    fn test<Conf: Config>(){
+        let max = BitSet::<Conf>::max_capacity();
+        let max_addressable = max_addressable_index::<Conf>();
+        let data_size = <Conf as Config>::DataBitBlock::size();
+        let level1_capacity = data_size * <Conf as Config>::Level1BitBlock::size();
+
+        let mut rng = rand::thread_rng();
+        let mut offsets = vec![0, 1, data_size - 1, data_size, data_size + 1, level1_capacity, level1_capacity + 3, max - 1, max, max_addressable];
+        for _ in 0..20 {
+            offsets.push(rng.gen_range(0..max_addressable));
+        }
+        for offset in offsets{
+            let set: BitSet<Conf> = (0..10)
+                .map(|_| rng.gen_range(0..max))
+                .chain([0, data_size - 1, data_size, level1_capacity - 1, max - 1])
+                .collect();
+            let expected: Vec<usize> = set.iter()
+                .map(|i| i + offset)
+                .filter(|i| *i < max_addressable)
+                .collect();
+
+            let shifted = OffsetBitSet::new(&set, offset);
+            assert_equal(shifted.iter(), expected.iter().copied());
+            assert_equal(shifted.block_iter().flatten(), expected.iter().copied());
+            for &i in &expected {
+                assert!(shifted.contains(i));
+            }
+            if offset > 0 {
+                assert!(!shifted.contains(offset - 1));
+            }
+
+            assert_eq!(shifted.is_empty(), expected.is_empty());
+            if expected.last().map_or(true, |&i| i < max) {
+                let materialized: BitSet<Conf> = expected.iter().copied().collect();
+                assert_eq!(shifted, materialized);
+            }
+        }
+    }
+
+    #[test]
+    fn offset_64_test(){
+        test::<_64bit>();
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn offset_256_test(){
+        test::<crate::config::_256bit>();
+    }
+
+    #[test]
+    fn offset_segments_test(){
+        type HiSparseBitset = BitSet<_64bit>;
+        let segment0: HiSparseBitset = [0, 1, 5, 999].into();
+        let segment1: HiSparseBitset = [0, 2, 5, 30_000].into();
+        let base1 = 1000;
+
+        let all = apply(Or, OffsetBitSet::new(&segment0, 0), OffsetBitSet::new(&segment1, base1));
+        assert_equal(all.iter(), [0, 1, 5, 999, 1000, 1002, 1005, 31_000]);
+
+        let filter: HiSparseBitset = (1000..2000).collect();
+        let and = apply(And, &filter, OffsetBitSet::new(apply(Or, &segment0, &segment1), base1));
+        assert_equal(and.iter(), [1000, 1001, 1002, 1005, 1999]);
+    }
+}
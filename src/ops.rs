@@ -5,14 +5,25 @@
 //! * [Or] - does not need to discard any blocks, since it is a merge operation by definition.
 //! * [Xor] - have [Or] performance.
 //! * [Sub] - traverse all left operand bitset blocks.
+//! * [AndNot] - same as [Sub].
+//! * [Implication] - visits every data block of `Conf`, like [ComplementBitSet].
+//! * [Nand], [Nor] - same as [Implication].
 //!
 //! You can make your own operation by implementing [BitSetOp].
 //!
+//! Unary [complement] is a lazy bitset itself - [ComplementBitSet].
+//!
+//! Boolean-valued [CheckOp]s - [SubsetCheck], [SupersetCheck], [DisjointCheck] -
+//! are applied with [check] and do not produce bitset.
+//!
 //! [apply]: crate::apply()
 //! [reduce]: crate::reduce()
+//! [ComplementBitSet]: crate::ComplementBitSet
 
-use std::ops::{BitAnd, BitOr, BitXor};
+use core::ops::{BitAnd, BitOr, BitXor};
 use crate::bit_block::BitBlock;
+use crate::{BitSetInterface, ComplementBitSet};
+use crate::bitset_interface::{bitset_is_disjoint, bitset_is_subset};
 
 // TODO: all operations should accept & instead?
 //       To work with [u64;N] more flawlessly?
@@ -71,6 +82,15 @@ impl BitSetOp for And {
         BitAnd::bitand(left, right)
     }
 }
+impl Associative for And {}
+
+/// Marker for operations, where `op(op(a, b), c) == op(a, op(b, c))`
+/// on both hierarchy and data levels.
+/// 
+/// Required by [apply3].
+/// 
+/// [apply3]: crate::apply3()
+pub trait Associative: BitSetOp {}
 
 /// Union
 /// 
@@ -91,6 +111,7 @@ impl BitSetOp for Or {
         BitOr::bitor(left, right)
     }
 }
+impl Associative for Or {}
 
 /// Symmetric difference.
 /// 
@@ -111,6 +132,7 @@ impl BitSetOp for Xor {
         BitXor::bitxor(left, right)
     }
 }
+impl Associative for Xor {}
 
 /// Difference (relative complement) left\right.
 /// 
@@ -130,4 +152,185 @@ impl BitSetOp for Sub {
     fn data_op<T: BitBlock>(left: T, right: T) -> T {
         left & (left ^ right)
     }
-}
\ No newline at end of file
+}
+
+/// `left & !right`. Same as [Sub].
+pub use self::Sub as AndNot;
+
+/// Complement of bitset, `!s`. Same as [ComplementBitSet::new].
+/// 
+/// Result is enormous for sparse `s` - use it only as an intermediate of
+/// composed operations, like NAND with filter: 
+/// `apply(And, &filter, complement(apply(And, &a, &b)))`.
+#[inline]
+pub fn complement<S: BitSetInterface>(s: S) -> ComplementBitSet<S> {
+    ComplementBitSet::new(s)
+}
+
+/// Material implication `left -> right`, or `!left | right`.
+/// 
+/// Result contains all indices, which are not in left, so hierarchy is 
+/// "full" - iterating it on its own visits every data block of `Conf`.
+/// Use it as an operand of intersection.
+/// 
+/// Implication is not associative - do not use it with [reduce].
+/// 
+/// [reduce]: crate::reduce()
+#[derive(Default, Copy, Clone)]
+pub struct Implication;
+impl BitSetOp for Implication {
+    const TRUSTED_HIERARCHY: bool = false;
+    const HIERARCHY_OPERANDS_CONTAIN_RESULT: bool = false;
+    
+    #[inline]
+    fn hierarchy_op<T: BitBlock>(_left: T, _right: T) -> T {
+        // Any block, not full in left, may have result bits.
+        T::ones()
+    }
+
+    #[inline]
+    fn data_op<T: BitBlock>(left: T, right: T) -> T {
+        (left ^ T::ones()) | right
+    }
+}
+
+/// `!(left & right)`.
+/// 
+/// Result contains all indices, which are not in both operands - for sparse
+/// operands it is almost [FullBitSet]. Hierarchy is "full" - iterating it 
+/// on its own visits every data block of `Conf`. Use it as an operand of 
+/// intersection, like `apply(And, &filter, apply(Nand, &a, &b))`.
+/// 
+/// Nand is not associative - do not use it with [reduce].
+/// 
+/// [FullBitSet]: crate::FullBitSet
+/// [reduce]: crate::reduce()
+#[derive(Default, Copy, Clone)]
+pub struct Nand;
+impl BitSetOp for Nand {
+    const TRUSTED_HIERARCHY: bool = false;
+    const HIERARCHY_OPERANDS_CONTAIN_RESULT: bool = false;
+    
+    #[inline]
+    fn hierarchy_op<T: BitBlock>(_left: T, _right: T) -> T {
+        // Blocks, empty in both operands, are full in result.
+        T::ones()
+    }
+
+    #[inline]
+    fn data_op<T: BitBlock>(left: T, right: T) -> T {
+        (left & right) ^ T::ones()
+    }
+}
+
+/// `!(left | right)`.
+/// 
+/// Result contains all indices, which are in neither operand. Same as [Nand],
+/// hierarchy is "full" - use it as an operand of intersection.
+/// 
+/// Nor is not associative - do not use it with [reduce].
+/// 
+/// [reduce]: crate::reduce()
+#[derive(Default, Copy, Clone)]
+pub struct Nor;
+impl BitSetOp for Nor {
+    const TRUSTED_HIERARCHY: bool = false;
+    const HIERARCHY_OPERANDS_CONTAIN_RESULT: bool = false;
+    
+    #[inline]
+    fn hierarchy_op<T: BitBlock>(_left: T, _right: T) -> T {
+        // Blocks, empty in both operands, are full in result.
+        T::ones()
+    }
+
+    #[inline]
+    fn data_op<T: BitBlock>(left: T, right: T) -> T {
+        (left | right) ^ T::ones()
+    }
+}
+
+/// Boolean-valued operation between two bitsets, for [check].
+/// 
+/// Implement this trait for creating your own check. Usually, 
+/// it is [is_empty()] of some lazy [apply] result - which stops 
+/// at the first found element.
+/// 
+/// [is_empty()]: crate::BitSetInterface::is_empty
+/// [apply]: crate::apply()
+pub trait CheckOp: Default + Copy + 'static {
+    fn check<L, R>(left: L, right: R) -> bool
+    where
+        L: BitSetInterface,
+        R: BitSetInterface<Conf = L::Conf>;
+}
+
+/// Applies [CheckOp] to `s1` and `s2`.
+/// 
+/// ```
+/// # use hi_sparse_bitset::BitSet;
+/// # use hi_sparse_bitset::config::_64bit;
+/// # use hi_sparse_bitset::ops::{check, DisjointCheck, SubsetCheck};
+/// let a: BitSet<_64bit> = [1, 2].into();
+/// let b: BitSet<_64bit> = [1, 2, 3].into();
+/// assert!(check(SubsetCheck, &a, &b));
+/// assert!(!check(DisjointCheck, &a, &b));
+/// ```
+#[inline]
+pub fn check<Op, S1, S2>(_: Op, s1: S1, s2: S2) -> bool
+where
+    Op: CheckOp,
+    S1: BitSetInterface,
+    S2: BitSetInterface<Conf = S1::Conf>,
+{
+    Op::check(s1, s2)
+}
+
+/// Is `left` subset of `right`? Same as [BitSetInterface::is_subset()].
+/// 
+/// `(left - right).is_empty()`. Traverses only `left` blocks, and stops at
+/// the first element of `left` not in `right`.
+#[derive(Default, Copy, Clone)]
+pub struct SubsetCheck;
+impl CheckOp for SubsetCheck {
+    #[inline]
+    fn check<L, R>(left: L, right: R) -> bool
+    where
+        L: BitSetInterface,
+        R: BitSetInterface<Conf = L::Conf>
+    {
+        bitset_is_subset(left, right)
+    }
+}
+
+/// Is `left` superset of `right`? Same as [BitSetInterface::is_superset()].
+/// 
+/// Same as [SubsetCheck] with swapped operands.
+#[derive(Default, Copy, Clone)]
+pub struct SupersetCheck;
+impl CheckOp for SupersetCheck {
+    #[inline]
+    fn check<L, R>(left: L, right: R) -> bool
+    where
+        L: BitSetInterface,
+        R: BitSetInterface<Conf = L::Conf>
+    {
+        bitset_is_subset(right, left)
+    }
+}
+
+/// Have `left` and `right` no elements in common? Same as [BitSetInterface::is_disjoint()].
+/// 
+/// `(left & right).is_empty()`. Traverses only blocks, present in both 
+/// hierarchies, and stops at the first common element.
+#[derive(Default, Copy, Clone)]
+pub struct DisjointCheck;
+impl CheckOp for DisjointCheck {
+    #[inline]
+    fn check<L, R>(left: L, right: R) -> bool
+    where
+        L: BitSetInterface,
+        R: BitSetInterface<Conf = L::Conf>
+    {
+        bitset_is_disjoint(left, right)
+    }
+}
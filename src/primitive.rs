@@ -1,5 +1,5 @@
-use std::fmt::Debug;
-use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign/*, Shr, ShrAssign*/};
+use core::fmt::Debug;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign/*, Shr, ShrAssign*/};
 
 // num_traits was just **TOO** hard to use with primitives...
 // Cast from/to concrete primitive was a final nail into num_trait's coffin.
@@ -33,6 +33,8 @@ pub trait Primitive:
     fn as_usize(self) -> usize;
     
     fn trailing_zeros(self) -> u32;
+    fn leading_zeros(self) -> u32;
+    fn count_ones(self) -> u32;
     fn wrapping_neg(self) -> Self;
     
     fn is_zero(self) -> bool;
@@ -62,6 +64,16 @@ macro_rules! impl_primitive {
                 self.trailing_zeros()
             }
 
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                self.leading_zeros()
+            }
+
+            #[inline]
+            fn count_ones(self) -> u32 {
+                self.count_ones()
+            }
+
             #[inline]
             fn wrapping_neg(self) -> Self {
                 self.wrapping_neg()
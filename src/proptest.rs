@@ -0,0 +1,59 @@
+//! Bitsets are generated as a collection of random indices.
+//! Shrinking removes elements and moves indices towards zero.
+
+use core::ops::RangeInclusive;
+use alloc::vec::Vec;
+use ::proptest::collection::{vec, VecStrategy};
+use ::proptest::strategy::{Map, Strategy};
+use crate::config::{Config, max_capacity};
+
+/// [Arbitrary] parameters for [BitSet] and [SmallBitSet].
+///
+/// Use with `any_with::<BitSet<_>>(params)`.
+///
+/// [Arbitrary]: ::proptest::arbitrary::Arbitrary
+/// [BitSet]: crate::BitSet
+/// [SmallBitSet]: crate::SmallBitSet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ArbitraryParams {
+    /// Maximum generated index. Clamped to bitset [max_capacity()].
+    ///
+    /// [max_capacity()]: crate::config::max_capacity
+    pub max_index: usize,
+    /// Fraction of `0..=max_index` indices that can be set.
+    /// Generated bitset have up to `density * (max_index + 1)` elements.
+    pub density: f64,
+    /// Maximum elements count.
+    pub max_len: usize,
+}
+
+/// `max_index` 10_000, `density` 0.1, `max_len` 1000.
+impl Default for ArbitraryParams {
+    #[inline]
+    fn default() -> Self {
+        Self{
+            max_index: 10_000,
+            density: 0.1,
+            max_len: 1000,
+        }
+    }
+}
+
+pub(crate) type ArbitraryStrategy<T> = Map<VecStrategy<RangeInclusive<usize>>, fn(Vec<usize>) -> T>;
+
+pub(crate) fn arbitrary_bitset<Conf, T>(params: ArbitraryParams) -> ArbitraryStrategy<T>
+where
+    Conf: Config,
+    T: FromIterator<usize> + core::fmt::Debug
+{
+    let max_index = core::cmp::min(params.max_index, max_capacity::<Conf>() - 1);
+    let len = (params.density.clamp(0.0, 1.0) * (max_index as f64 + 1.0)).ceil() as usize;
+    let len = core::cmp::min(len, params.max_len);
+    vec(0..=max_index, 0..=len).prop_map(collect_indices as fn(Vec<usize>) -> T)
+}
+
+/// Duplicates are merged.
+#[inline]
+fn collect_indices<T: FromIterator<usize>>(indices: Vec<usize>) -> T {
+    indices.into_iter().collect()
+}
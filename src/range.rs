@@ -0,0 +1,218 @@
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Bound, RangeBounds};
+use crate::bit_block::BitBlock;
+use crate::internals::impl_bitset;
+use crate::bitset_interface::{BitSetBase, LevelMasks, LevelMasksIterExt};
+use crate::config::{Config, max_capacity};
+use crate::level_indices;
+
+/// Bitset with all indices of a single contiguous range.
+/// 
+/// Masks are computed on the fly - no memory, instant construction.
+/// Useful as a filter: `apply(And, &set, RangeBitSet::new(1000..=2000))`.
+pub struct RangeBitSet<Conf>{
+    /// Inclusive. `None` for empty range.
+    range: Option<(usize, usize)>,
+    phantom: PhantomData<Conf>
+}
+
+impl<Conf: Config> RangeBitSet<Conf>{
+    /// `range` is clamped to `0..`[max_capacity()].
+    /// 
+    /// [max_capacity()]: crate::config::max_capacity
+    #[inline]
+    pub fn new<R: RangeBounds<usize>>(range: R) -> Self {
+        let first = match range.start_bound() {
+            Bound::Included(&i) => Some(i),
+            Bound::Excluded(&i) => i.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let last = match range.end_bound() {
+            Bound::Included(&i) => Some(i),
+            Bound::Excluded(&i) => i.checked_sub(1),
+            Bound::Unbounded => Some(usize::MAX),
+        };
+        let range = match (first, last) {
+            (Some(first), Some(last)) => {
+                let last = core::cmp::min(last, max_capacity::<Conf>() - 1);
+                if first <= last {
+                    Some((first, last))
+                } else {
+                    None
+                }
+            }
+            _ => None
+        };
+        Self{ range, phantom: PhantomData }
+    }
+}
+
+/// Empty range.
+impl<Conf> Default for RangeBitSet<Conf>{
+    #[inline]
+    fn default() -> Self {
+        Self{ range: None, phantom: PhantomData }
+    }
+}
+
+impl<Conf> Clone for RangeBitSet<Conf>{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Conf> Copy for RangeBitSet<Conf>{}
+
+/// Block with bits `first..=last` raised.
+#[inline]
+pub(crate) fn ones_in_range<B: BitBlock>(first: usize, last: usize) -> B {
+    let mut block = B::zero();
+    for (i, word) in block.as_array_mut().iter_mut().enumerate(){
+        let word_first = i * 64;
+        let word_last  = word_first + 63;
+        if word_last < first || word_first > last {
+            continue;
+        }
+        let from = first.saturating_sub(word_first);
+        let to   = core::cmp::min(last, word_last) - word_first;
+        *word = (u64::MAX >> (63 - to)) & (u64::MAX << from);
+    }
+    block
+}
+
+impl<Conf: Config> BitSetBase for RangeBitSet<Conf> {
+    type Conf = Conf;
+    const TRUSTED_HIERARCHY: bool = true;
+}
+
+impl<Conf: Config> LevelMasks for RangeBitSet<Conf> {
+    #[inline]
+    fn level0_mask(&self) -> <Self::Conf as Config>::Level0BitBlock {
+        let Some((first, last)) = self.range else {
+            return BitBlock::zero();
+        };
+        let (first0, _, _) = level_indices::<Conf>(first);
+        let (last0 , _, _) = level_indices::<Conf>(last);
+        ones_in_range(first0, last0)
+    }
+
+    #[inline]
+    unsafe fn level1_mask(&self, level0_index: usize)
+        -> <Self::Conf as Config>::Level1BitBlock
+    {
+        let Some((first, last)) = self.range else {
+            return BitBlock::zero();
+        };
+        let (first0, first1, _) = level_indices::<Conf>(first);
+        let (last0 , last1 , _) = level_indices::<Conf>(last);
+        if level0_index < first0 || level0_index > last0 {
+            return BitBlock::zero();
+        }
+        let begin = if level0_index == first0 { first1 } else { 0 };
+        let end   = if level0_index == last0  { last1  } else { Conf::Level1BitBlock::size() - 1 };
+        ones_in_range(begin, end)
+    }
+
+    #[inline]
+    unsafe fn data_mask(&self, level0_index: usize, level1_index: usize)
+        -> <Self::Conf as Config>::DataBitBlock
+    {
+        let Some((first, last)) = self.range else {
+            return BitBlock::zero();
+        };
+        let (first0, first1, first_data) = level_indices::<Conf>(first);
+        let (last0 , last1 , last_data ) = level_indices::<Conf>(last);
+        let block = (level0_index, level1_index);
+        if block < (first0, first1) || block > (last0, last1) {
+            return BitBlock::zero();
+        }
+        let begin = if block == (first0, first1) { first_data } else { 0 };
+        let end   = if block == (last0, last1)   { last_data  } else { Conf::DataBitBlock::size() - 1 };
+        ones_in_range(begin, end)
+    }
+}
+
+impl<Conf: Config> LevelMasksIterExt for RangeBitSet<Conf> {
+    type IterState = ();
+    
+    /// (self, level0_index)
+    type Level1BlockData = (Self, usize);
+
+    #[inline]
+    fn make_iter_state(&self) -> Self::IterState {}
+    
+    #[inline]
+    unsafe fn drop_iter_state(&self, _: &mut ManuallyDrop<Self::IterState>) {}
+
+    #[inline]
+    unsafe fn init_level1_block_data(
+        &self,
+        _: &mut Self::IterState,
+        level1_block_data: &mut MaybeUninit<Self::Level1BlockData>,
+        level0_index: usize
+    ) -> (<Self::Conf as Config>::Level1BitBlock, bool) {
+        level1_block_data.write((*self, level0_index));
+        (self.level1_mask(level0_index), true)
+    }
+
+    #[inline]
+    unsafe fn data_mask_from_block_data(
+        level1_block_data: &Self::Level1BlockData, level1_index: usize
+    ) -> <Self::Conf as Config>::DataBitBlock {
+        let (this, level0_index) = level1_block_data;
+        this.data_mask(*level0_index, level1_index)
+    }
+}
+
+impl_bitset!(
+    impl<Conf> for RangeBitSet<Conf> where Conf: Config
+);
+
+#[cfg(test)]
+mod test{
+    use itertools::assert_equal;
+    use rand::Rng;
+    use crate::{apply, BitSet, BitSetInterface};
+    use crate::config::{_64bit, Config};
+    use crate::ops::And;
+    use super::RangeBitSet;
+
+    fn test<Conf: Config>(){
+        let max = BitSet::<Conf>::max_capacity();
+        
+        assert!(RangeBitSet::<Conf>::new(10..10).is_empty());
+        assert!(RangeBitSet::<Conf>::new(10..=9).is_empty());
+        assert_equal(RangeBitSet::<Conf>::new(..3).iter(), 0..3);
+        assert_equal(RangeBitSet::<Conf>::new(max-3..).iter(), max-3..max);
+        
+        let mut rng = rand::thread_rng();
+        for _ in 0..20{
+            let first = rng.gen_range(0..20_000);
+            let last  = first + rng.gen_range(0..20_000);
+            let range = RangeBitSet::<Conf>::new(first..=last);
+            assert_equal(range.iter(), first..=last);
+            assert_eq!(range.first(), Some(first));
+            assert_eq!(range.last(), Some(last));
+            
+            let set: BitSet<Conf> = (first..=last).collect();
+            assert_eq!(range, set);
+            
+            let other: BitSet<Conf> = (0..50_000).step_by(3).collect();
+            let filtered = apply(And, &other, range);
+            assert_equal(filtered.iter(), (0..50_000).step_by(3).filter(|i| (first..=last).contains(i)));
+        }
+    }
+
+    #[test]
+    fn range_64_test(){
+        test::<_64bit>();
+    }
+    
+    #[cfg(feature = "simd")]
+    #[test]
+    fn range_256_test(){
+        test::<crate::config::_256bit>();
+    }
+}
@@ -1,11 +1,73 @@
-use std::marker::PhantomData;
-use std::mem::{ManuallyDrop, MaybeUninit};
-use std::ptr::NonNull;
-use crate::config::{Config, max_addressable_index};
-use crate::{BitBlock, BitSetBase, level_indices};
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+use core::ops::ControlFlow::{Break, Continue};
+use core::ptr::NonNull;
+use alloc::vec::Vec;
+use crate::config::{Config, ConfigCheck, DefaultBlockIterator, max_capacity};
+use crate::{BitBlock, BitSetBase, data_block_start_index, DataBlock, level_indices};
 use crate::bitset_interface::{LevelMasks, LevelMasksIterExt};
+use crate::full::first_n_ones;
 use crate::level::{IBlock, Level};
+use crate::ops::BitSetOp;
 use crate::primitive::Primitive;
+use crate::range::RangeBitSet;
+
+#[cfg(feature = "fixedbitset")]
+const FIXED_BLOCK_BITS: usize = fixedbitset::Block::BITS as usize;
+/// `FixedBitSet` blocks in `u64` word.
+#[cfg(feature = "fixedbitset")]
+const FIXED_BLOCKS_PER_WORD: usize = 64 / FIXED_BLOCK_BITS;
+
+/// Memory used by [BitSet]/[SmallBitSet], in bytes.
+/// 
+/// Returned by `memory_usage()`. `*_blocks_bytes` - memory of blocks in use
+/// (including blocks, kept for reuse after removal), 
+/// `*_allocated_capacity_bytes` - memory actually allocated.
+/// Heap memory, owned by [SmallBitSet] level1 blocks, is included in both.
+/// 
+/// [BitSet]: crate::BitSet
+/// [SmallBitSet]: crate::SmallBitSet
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MemoryUsage{
+    /// Level0 block is stored inline.
+    pub level0_bytes: usize,
+    pub level1_blocks_bytes: usize,
+    pub level1_allocated_capacity_bytes: usize,
+    pub data_blocks_bytes: usize,
+    pub data_allocated_capacity_bytes: usize,
+}
+
+impl MemoryUsage{
+    /// All memory occupied by bitset.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.level0_bytes 
+        + self.level1_allocated_capacity_bytes 
+        + self.data_allocated_capacity_bytes
+    }
+}
+
+/// Hierarchy statistics of [BitSet]/[SmallBitSet].
+/// 
+/// Returned by `level_stats()`. Only blocks in use are counted - 
+/// blocks kept for reuse after removal are not.
+/// 
+/// [BitSet]: crate::BitSet
+/// [SmallBitSet]: crate::SmallBitSet
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LevelStats{
+    /// Raised level0 bits / level0 block size.
+    pub level0_fill_fraction: f64,
+    pub level1_block_count: usize,
+    /// Raised bits / level1 block size, for each level1 block in use. 
+    /// In level0 order.
+    pub level1_fill_fractions: Vec<f64>,
+    pub data_block_count: usize,
+    pub total_set_bits: usize,
+    /// 0, if bitset is empty.
+    pub avg_bits_per_data_block: f64,
+}
 
 pub struct RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
 where
@@ -16,6 +78,8 @@ where
     level0: Level0Block,
     level1: Level<Level1Block>,
     data  : Level<LevelDataBlock>,
+    /// Incremented on each mutation.
+    generation: u64,
     phantom: PhantomData<Conf>
 }
 
@@ -32,6 +96,7 @@ where
             level0: self.level0.clone(),
             level1: self.level1.clone(),
             data: self.data.clone(),
+            generation: 0,
             phantom: Default::default(),
         }
     }
@@ -46,10 +111,12 @@ where
 {
     #[inline]
     fn default() -> Self {
+        let () = ConfigCheck::<Conf>::VALID;
         Self{
             level0: Default::default(),
             level1: Default::default(),
             data: Default::default(),
+            generation: 0,
             phantom: PhantomData
         }
     }
@@ -71,6 +138,66 @@ where
     }
 }
 
+impl<Conf, Level0Block, Level1Block, LevelDataBlock> Extend<usize> for RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock,
+    Level1Block: IBlock,
+    LevelDataBlock: IBlock,
+{
+    #[inline]
+    fn extend<T: IntoIterator<Item=usize>>(&mut self, iter: T) {
+        for i in iter{
+            self.insert(i);
+        }
+    }
+}
+
+impl<'a, Conf, Level0Block, Level1Block, LevelDataBlock> Extend<&'a usize> for RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock,
+    Level1Block: IBlock,
+    LevelDataBlock: IBlock,
+{
+    #[inline]
+    fn extend<T: IntoIterator<Item=&'a usize>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<Conf, Level0Block, Level1Block, LevelDataBlock> Extend<DataBlock<Conf::DataBitBlock>> for RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock<Mask = Conf::Level0BitBlock>,
+    Level1Block: IBlock<Mask = Conf::Level1BitBlock>,
+    LevelDataBlock: IBlock<Mask = Conf::DataBitBlock>,
+{
+    /// Blocks are OR-ed into existing data blocks.
+    #[inline]
+    fn extend<T: IntoIterator<Item=DataBlock<Conf::DataBitBlock>>>(&mut self, iter: T) {
+        for block in iter{
+            self.insert_datablock(block);
+        }
+    }
+}
+
+impl<Conf, Level0Block, Level1Block, LevelDataBlock> FromIterator<DataBlock<Conf::DataBitBlock>> for RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock<Mask = Conf::Level0BitBlock>,
+    Level1Block: IBlock<Mask = Conf::Level1BitBlock>,
+    LevelDataBlock: IBlock<Mask = Conf::DataBitBlock>,
+{
+    /// Blocks with the same `start_index` are OR-ed.
+    #[inline]
+    fn from_iter<T: IntoIterator<Item=DataBlock<Conf::DataBitBlock>>>(iter: T) -> Self {
+        let mut this = Self::default();
+        this.extend(iter);
+        this
+    }
+}
+
 impl<Conf, Level0Block, Level1Block, LevelDataBlock, const N: usize> From<[usize; N]> for RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
 where
     Conf: Config,
@@ -101,11 +228,179 @@ where
     /// [BitSet]: crate::BitSet
     #[inline]
     pub const fn max_capacity() -> usize {
-        // We occupy one block for "empty" at each level, except root.
-        max_addressable_index::<Conf>()
-            - (1 << Level1Block::Mask::SIZE_POT_EXPONENT) * (1 << LevelDataBlock::Mask::SIZE_POT_EXPONENT)
-            - (1 << LevelDataBlock::Mask::SIZE_POT_EXPONENT)
-    }      
+        max_capacity::<Conf>()
+    }
+    
+    /// Allocates enough memory to hold `data_blocks` data blocks, 
+    /// with their hierarchy, without reallocations.
+    #[inline]
+    pub fn with_capacity_hint(data_blocks: usize) -> Self {
+        let () = ConfigCheck::<Conf>::VALID;
+        let level1_blocks = core::cmp::min(data_blocks, Level0Block::Mask::size());
+        Self{
+            level0: Default::default(),
+            level1: Level::with_capacity(level1_blocks),
+            data: Level::with_capacity(data_blocks),
+            generation: 0,
+            phantom: PhantomData
+        }
+    }
+    
+    /// Mutations counter. Incremented by each insert, remove or bulk mutation,
+    /// even if it did not actually change the set. Clone starts from 0.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+    
+    /// Removes all elements. Keeps allocated memory.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.generation += 1;
+        self.level0 = Default::default();
+        self.level1.clear();
+        self.data.clear();
+    }
+    
+    /// Moves all alive blocks to new tightly packed storage, 
+    /// without empty blocks, and releases the old one.
+    /// 
+    /// O(N) of alive blocks.
+    pub fn shrink_to_fit(&mut self) {
+        let level0_mask = *self.level0.mask();
+        let level1_len = level0_mask.count_ones();
+        let mut data_len = 0;
+        let _ = level0_mask.traverse_bits(|level0_index|{
+            let level1_block_index = unsafe{ self.level0.get_or_zero(level0_index) }.as_usize();
+            let level1_block = unsafe{ self.level1.blocks().get_unchecked(level1_block_index) };
+            data_len += level1_block.mask().count_ones();
+            Continue(())
+        });
+        self.rebuild(level1_len, data_len);
+    }
+    
+    /// Renumbers all alive blocks contiguously, in index order.
+    /// Keeps allocated capacity.
+    /// 
+    /// O(N) of alive blocks.
+    pub fn compact(&mut self) {
+        // with_capacity reserves one more, for empty block.
+        let level1_capacity = self.level1.capacity() - 1;
+        let data_capacity   = self.data.capacity() - 1;
+        self.rebuild(level1_capacity, data_capacity);
+    }
+    
+    /// Moves all alive blocks, in index order, to new storage of given capacities.
+    fn rebuild(&mut self, level1_capacity: usize, data_capacity: usize) {
+        let level0_mask = *self.level0.mask();
+        let mut level0 = Level0Block::default();
+        let mut level1 = Level::with_capacity(level1_capacity);
+        let mut data   = Level::with_capacity(data_capacity);
+        
+        let _ = level0_mask.traverse_bits(|level0_index|{
+            let level1_block_index = unsafe{ self.level0.get_or_zero(level0_index) }.as_usize();
+            let old_level1_block = unsafe{ self.level1.blocks().get_unchecked(level1_block_index) };
+            
+            let mut level1_block = Level1Block::default();
+            let _ = old_level1_block.mask().traverse_bits(|level1_index|{
+                let data_block_index = unsafe{ old_level1_block.get_or_zero(level1_index) }.as_usize();
+                let data_block = core::mem::take(unsafe{
+                    self.data.blocks_mut().get_unchecked_mut(data_block_index)
+                });
+                let data_block_index = data.push_block(data_block);
+                unsafe{
+                    level1_block.get_or_insert(level1_index, ||Primitive::from_usize(data_block_index));
+                }
+                Continue(())
+            });
+            
+            let level1_block_index = level1.push_block(level1_block);
+            unsafe{
+                level0.get_or_insert(level0_index, ||Primitive::from_usize(level1_block_index));
+            }
+            Continue(())
+        });
+        
+        self.level0 = level0;
+        self.level1 = level1;
+        self.data   = data;
+    }
+    
+    pub fn memory_usage(&self) -> MemoryUsage {
+        use core::mem::size_of;
+        let level1_heap = self.level1.blocks_heap_memory_usage();
+        let data_heap   = self.data.blocks_heap_memory_usage();
+        MemoryUsage{
+            level0_bytes: size_of::<Level0Block>() + self.level0.heap_memory_usage(),
+            level1_blocks_bytes: self.level1.len() * size_of::<Level1Block>() + level1_heap,
+            level1_allocated_capacity_bytes: self.level1.capacity() * size_of::<Level1Block>() + level1_heap,
+            data_blocks_bytes: self.data.len() * size_of::<LevelDataBlock>() + data_heap,
+            data_allocated_capacity_bytes: self.data.capacity() * size_of::<LevelDataBlock>() + data_heap,
+        }
+    }
+    
+    /// Calls `f` for each level1 block in use, in level0 order, with its mask 
+    /// and raised bits count of all its data blocks.
+    fn traverse_level1_blocks(&self, mut f: impl FnMut(Level1Block::Mask, usize)) {
+        let level0_mask = *self.level0.mask();
+        let _ = level0_mask.traverse_bits(|level0_index| unsafe{
+            let level1_block_index = self.level0.get_or_zero(level0_index).as_usize();
+            let level1_block = self.level1.blocks().get_unchecked(level1_block_index);
+            let level1_mask = *level1_block.mask();
+            let mut data_bits = 0;
+            let _ = level1_mask.traverse_bits(|level1_index|{
+                let data_block_index = level1_block.get_or_zero(level1_index).as_usize();
+                let data_block = self.data.blocks().get_unchecked(data_block_index);
+                data_bits += data_block.mask().count_ones();
+                Continue(())
+            });
+            f(level1_mask, data_bits);
+            Continue(())
+        });
+    }
+    
+    /// O(N) of blocks in use.
+    pub fn level_stats(&self) -> LevelStats {
+        let level0_mask = *self.level0.mask();
+        let mut stats = LevelStats{
+            level0_fill_fraction: level0_mask.count_ones() as f64 / Conf::Level0BitBlock::size() as f64,
+            level1_block_count: level0_mask.count_ones(),
+            level1_fill_fractions: Vec::with_capacity(level0_mask.count_ones()),
+            ..Default::default()
+        };
+        self.traverse_level1_blocks(|level1_mask, data_bits|{
+            stats.level1_fill_fractions.push(
+                level1_mask.count_ones() as f64 / Conf::Level1BitBlock::size() as f64
+            );
+            stats.data_block_count += level1_mask.count_ones();
+            stats.total_set_bits += data_bits;
+        });
+        if stats.data_block_count != 0 {
+            stats.avg_bits_per_data_block = stats.total_set_bits as f64 / stats.data_block_count as f64;
+        }
+        stats
+    }
+    
+    /// O(N) of blocks in use.
+    pub fn population_count_by_level(&self) -> [usize; 3] {
+        let mut counts = [self.level0.mask().count_ones(), 0, 0];
+        self.traverse_level1_blocks(|level1_mask, data_bits|{
+            counts[1] += level1_mask.count_ones();
+            counts[2] += data_bits;
+        });
+        counts
+    }
+    
+    /// [memory_usage()] total, without blocks' own heap memory - O(1).
+    /// 
+    /// [memory_usage()]: Self::memory_usage
+    #[inline]
+    pub fn approximate_memory_usage(&self) -> usize {
+        use core::mem::size_of;
+        size_of::<Level0Block>()
+        + self.level1.capacity() * size_of::<Level1Block>()
+        + self.data.capacity() * size_of::<LevelDataBlock>()
+    }
     
     #[inline]
     fn is_in_range(index: usize) -> bool{
@@ -134,15 +429,15 @@ where
         };
     }
     
+    /// Returns data block index. 
+    /// 
+    /// Inserts empty data block, with all hierarchy path, if there is no one.
+    /// 
     /// # Safety
-    ///
-    /// Will panic, if `index` is out of range.
-    pub fn insert(&mut self, index: usize){
-        assert!(Self::is_in_range(index), "{index} index out of range!");
-
-        // That's indices to next level
-        let (level0_index, level1_index, data_index) = Self::level_indices(index);
-
+    /// 
+    /// Indices are not checked.
+    #[inline]
+    unsafe fn get_or_insert_data_block(&mut self, level0_index: usize, level1_index: usize) -> usize {
         // 1. Level0
         let level1_block_index = unsafe{
             self.level0.get_or_insert(level0_index, ||{
@@ -152,13 +447,58 @@ where
         }.as_usize();
 
         // 2. Level1
-        let data_block_index = unsafe{
+        unsafe{
             let level1_block = self.level1.blocks_mut().get_unchecked_mut(level1_block_index);
             level1_block.get_or_insert(level1_index, ||{
                 let block_index = self.data.insert_block();
                 Primitive::from_usize(block_index)
             })
-        }.as_usize();
+        }.as_usize()
+    }
+    
+    /// Removes empty data block from hierarchy. 
+    /// Removes its level1 block too, if it became empty.
+    /// 
+    /// # Safety
+    /// 
+    /// * Data block at `data_block_index` must be empty.
+    /// * All indices must be valid, and point to the same data block.
+    #[inline]
+    unsafe fn remove_empty_data_block(
+        &mut self, 
+        level0_index: usize, level1_index: usize, 
+        level1_block_index: usize, data_block_index: usize
+    ){
+        // remove data block
+        self.data.remove_empty_block_unchecked(data_block_index);
+
+        // remove pointer from level1
+        let level1_block = self.level1.blocks_mut().get_unchecked_mut(level1_block_index);
+        level1_block.remove_unchecked(level1_index);
+
+        if level1_block.is_empty(){
+            // remove level1 block
+            self.level1.remove_empty_block_unchecked(level1_block_index);
+
+            // remove pointer from level0
+            self.level0.remove_unchecked(level0_index);
+        }        
+    }
+    
+    /// # Safety
+    ///
+    /// Will panic, if `index` is out of range.
+    pub fn insert(&mut self, index: usize){
+        assert!(Self::is_in_range(index), "{index} index out of range!");
+        self.generation += 1;
+
+        // That's indices to next level
+        let (level0_index, level1_index, data_index) = Self::level_indices(index);
+
+        // 1-2. Level0, Level1
+        let data_block_index = unsafe{
+            self.get_or_insert_data_block(level0_index, level1_index)
+        };
 
         // 3. Data level
         unsafe{
@@ -169,6 +509,7 @@ where
     
     /// Returns false if index is invalid/not in bitset.
     pub fn remove(&mut self, index: usize) -> bool {
+        self.generation += 1;
         if !Self::is_in_range(index){
             return false;
         }
@@ -189,27 +530,605 @@ where
             //if existed{
                 // 3. Remove free blocks
                 if data_block.is_empty(){
-                    // remove data block
-                    self.data.remove_empty_block_unchecked(data_block_index);
-
-                    // remove pointer from level1
-                    let level1_block = self.level1.blocks_mut().get_unchecked_mut(level1_block_index);
-                    level1_block.remove_unchecked(level1_index);
+                    self.remove_empty_data_block(
+                        level0_index, level1_index, 
+                        level1_block_index, data_block_index
+                    );
+                }
+            //}
+            existed
+        }
+    }
+}
 
-                    if level1_block.is_empty(){
-                        // remove level1 block
-                        self.level1.remove_empty_block_unchecked(level1_block_index);
+impl<Conf, Level0Block, Level1Block, LevelDataBlock> RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock<Mask = Conf::Level0BitBlock>,
+    Level1Block: IBlock<Mask = Conf::Level1BitBlock>,
+    LevelDataBlock: IBlock<Mask = Conf::DataBitBlock>
+{
+    /// Replaces data block mask with `f(start_index, mask)` result.
+    /// Data blocks that became empty are removed.
+    #[inline]
+    fn update_data_blocks<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, Conf::DataBitBlock) -> Conf::DataBitBlock
+    {
+        self.generation += 1;
+        // Masks are copied, so we can mutate hierarchy during traverse.
+        let level0_mask = *self.level0.mask();
+        let _ = level0_mask.traverse_bits(|level0_index|{
+            let level1_block_index = unsafe{
+                self.level0.get_or_zero(level0_index)
+            }.as_usize();
+            let level1_mask = unsafe{
+                *self.level1.blocks().get_unchecked(level1_block_index).mask()
+            };
+            let _ = level1_mask.traverse_bits(|level1_index|unsafe{
+                let data_block_index = self.level1.blocks()
+                    .get_unchecked(level1_block_index)
+                    .get_or_zero(level1_index).as_usize();
+                let data_block = self.data.blocks_mut().get_unchecked_mut(data_block_index);
+                
+                let start_index = data_block_start_index::<Conf>(level0_index, level1_index);
+                let mask = f(start_index, *data_block.mask());
+                *data_block.mask_mut() = mask;
+                if mask.is_zero(){
+                    self.remove_empty_data_block(
+                        level0_index, level1_index, 
+                        level1_block_index, data_block_index
+                    );
+                }
+                Continue(())
+            });
+            Continue(())
+        });
+    }
+    
+    
+    /// Replaces data block mask with `f(mask, other_mask)` result.
+    /// Data blocks that became empty are removed.
+    /// 
+    /// Traverse `self` hierarchy. `other` hierarchy used only for accessing
+    /// its data blocks. `f(mask, zero)` is called for data blocks that do not 
+    /// exist in `other` hierarchy.
+    #[inline]
+    fn update_data_blocks_with<S, F>(&mut self, other: S, mut f: F)
+    where
+        S: LevelMasksIterExt<Conf = Conf>,
+        F: FnMut(Conf::DataBitBlock, Conf::DataBitBlock) -> Conf::DataBitBlock
+    {
+        self.generation += 1;
+        let mut state = ManuallyDrop::new(other.make_iter_state());
+        let mut level1_block_data = MaybeUninit::new(Default::default());
+        
+        let level0_mask = *self.level0.mask();
+        let _ = level0_mask.traverse_bits(|level0_index|{
+            let (other_level1_mask, other_valid) = unsafe {
+                level1_block_data.assume_init_drop();
+                other.init_level1_block_data(&mut state, &mut level1_block_data, level0_index)
+            };
+            
+            let level1_block_index = unsafe{
+                self.level0.get_or_zero(level0_index)
+            }.as_usize();
+            let level1_mask = unsafe{
+                *self.level1.blocks().get_unchecked(level1_block_index).mask()
+            };
+            let _ = level1_mask.traverse_bits(|level1_index|unsafe{
+                let other_mask = 
+                    if other_valid & other_level1_mask.get_bit(level1_index){
+                        S::data_mask_from_block_data(level1_block_data.assume_init_ref(), level1_index)
+                    } else {
+                        BitBlock::zero()
+                    }; 
+                
+                let data_block_index = self.level1.blocks()
+                    .get_unchecked(level1_block_index)
+                    .get_or_zero(level1_index).as_usize();
+                let data_block = self.data.blocks_mut().get_unchecked_mut(data_block_index);
+                
+                let mask = f(*data_block.mask(), other_mask);
+                *data_block.mask_mut() = mask;
+                if mask.is_zero(){
+                    self.remove_empty_data_block(
+                        level0_index, level1_index, 
+                        level1_block_index, data_block_index
+                    );
+                }
+                Continue(())
+            });
+            Continue(())
+        });
+        
+        unsafe{
+            level1_block_data.assume_init_drop();
+            other.drop_iter_state(&mut state);
+        }
+    }
+    
+    /// `block` bits are OR-ed into existing data block.
+    /// 
+    /// Empty `block` is no-op.
+    /// 
+    /// # Safety
+    /// 
+    /// Will panic, if `block.start_index` is out of range.
+    pub fn insert_datablock(&mut self, block: DataBlock<Conf::DataBitBlock>){
+        assert!(Self::is_in_range(block.start_index), "{} index out of range!", block.start_index);
+        if block.is_empty(){
+            return;
+        }
+        self.generation += 1;
+        
+        let (level0_index, level1_index, _) = Self::level_indices(block.start_index);
+        unsafe{
+            let data_block_index = self.get_or_insert_data_block(level0_index, level1_index);
+            let data_block = self.data.blocks_mut().get_unchecked_mut(data_block_index);
+            let mask = *data_block.mask() | block.bit_block; 
+            *data_block.mask_mut() = mask;
+        }
+    }
+    
+    /// Removes data block, containing `index`, with all its bits.
+    ///
+    /// Returns false, if there were no such data block.
+    pub fn remove_datablock(&mut self, index: usize) -> bool {
+        self.generation += 1;
+        if !Self::is_in_range(index){
+            return false;
+        }
 
-                        // remove pointer from level0
-                        self.level0.remove_unchecked(level0_index);
+        let (level0_index, level1_index, _) = Self::level_indices(index);
+        let Some((level1_block_index, data_block_index)) = self.get_block_indices(level0_index, level1_index) else {
+            return false;
+        };
+        unsafe{
+            let data_block = self.data.blocks_mut().get_unchecked_mut(data_block_index);
+            *data_block.mask_mut() = BitBlock::zero();
+            self.remove_empty_data_block(
+                level0_index, level1_index,
+                level1_block_index, data_block_index
+            );
+        }
+        true
+    }
+    
+    /// Bitset with indices from `range`, for which `f` returns true.
+    /// 
+    /// Data blocks are filled one at a time, then inserted.
+    /// 
+    /// # Safety
+    /// 
+    /// Will panic, if `range` is out of range.
+    pub fn from_fn<R, F>(range: R, mut f: F) -> Self
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(usize) -> bool
+    {
+        let first = match range.start_bound() {
+            Bound::Included(&i) => Some(i),
+            Bound::Excluded(&i) => i.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let last = match range.end_bound() {
+            Bound::Included(&i) => Some(i),
+            Bound::Excluded(&i) => i.checked_sub(1),
+            Bound::Unbounded => Some(Self::max_capacity() - 1),
+        };
+        
+        let mut this = Self::default();
+        let (Some(first), Some(last)) = (first, last) else {
+            return this;
+        };
+        if first > last {
+            return this;
+        }
+        assert!(last < Self::max_capacity(), "{} index out of range!", last);
+        let end = last + 1;
+        
+        let block_size = Conf::DataBitBlock::size();
+        let mut start_index = first & !(block_size - 1);
+        while start_index < end {
+            let mut bit_block = Conf::DataBitBlock::zero();
+            let block_first = core::cmp::max(first, start_index);
+            let block_end   = core::cmp::min(end, start_index + block_size);
+            for index in block_first..block_end {
+                if f(index) {
+                    bit_block.set_bit::<true>(index - start_index);
+                }
+            }
+            this.insert_datablock(DataBlock{ start_index, bit_block });
+            start_index += block_size;
+        }
+        this
+    }
+    
+    /// Indices of the same data block are OR-ed together, then each 
+    /// data block is inserted at once.
+    /// 
+    /// # Safety
+    /// 
+    /// Will panic, if any index is out of range.
+    /// `indices` must be sorted (checked with debug assertion).
+    pub fn from_sorted_slice(indices: &[usize]) -> Self {
+        let mut this = Self::default();
+        this.union_with_sorted_iter(indices.iter().copied());
+        this
+    }
+    
+    /// Indices of the same data block are gathered into one block,
+    /// which is inserted with a single hierarchy traversal.
+    pub fn union_with_sorted_iter<I>(&mut self, indices: I)
+    where
+        I: IntoIterator<Item = usize>
+    {
+        let block_size = Conf::DataBitBlock::size();
+        let mut block = DataBlock{ start_index: 0, bit_block: Conf::DataBitBlock::zero() };
+        let mut prev_index = 0;
+        for index in indices {
+            debug_assert!(prev_index <= index, "indices must be sorted!");
+            prev_index = index;
+            
+            let start_index = index & !(block_size - 1);
+            if start_index != block.start_index {
+                let new_block = DataBlock{ start_index, bit_block: Conf::DataBitBlock::zero() };
+                self.insert_datablock(core::mem::replace(&mut block, new_block));
+            }
+            block.bit_block.set_bit::<true>(index - start_index);
+        }
+        self.insert_datablock(block);
+    }
+    
+    /// Bitset with all indices of `ranges`. Inverse of `iter_ranges()`.
+    /// 
+    /// Each range is merged a data block at a time.
+    /// 
+    /// # Safety
+    /// 
+    /// Will panic, if any range is out of range.
+    pub fn from_ranges<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = RangeInclusive<usize>>
+    {
+        let mut this = Self::default();
+        for range in ranges{
+            if range.is_empty(){
+                continue;
+            }
+            assert!(*range.end() < Self::max_capacity(), "{} index out of range!", range.end());
+            this.union_update(RangeBitSet::<Conf>::new(range));
+        }
+        this
+    }
+    
+    /// Folds `sets` level by level: for each resulting level0 index - level1 masks
+    /// of all `sets`, then for each resulting level1 index - data masks of all `sets`.
+    /// 
+    /// No [ReduceCache] involved - no limit on `sets` len.
+    /// 
+    /// [ReduceCache]: crate::cache::ReduceCache
+    pub fn apply_batch<Op, S>(_: Op, sets: &[S]) -> Self
+    where
+        Op: BitSetOp,
+        for<'a> &'a S: LevelMasks<Conf = Conf>
+    {
+        let mut this = Self::default();
+        let Some((first, rest)) = sets.split_first() else {
+            return this;
+        };
+        let level0_mask = rest.iter().fold(first.level0_mask(), |mask, set| 
+            Op::hierarchy_op(mask, set.level0_mask())
+        );
+        let _ = level0_mask.traverse_bits(|level0_index| unsafe{
+            let level1_mask = rest.iter().fold(first.level1_mask(level0_index), |mask, set|
+                Op::hierarchy_op(mask, set.level1_mask(level0_index))
+            );
+            level1_mask.traverse_bits(|level1_index|{
+                let bit_block = rest.iter().fold(first.data_mask(level0_index, level1_index), |mask, set|
+                    Op::data_op(mask, set.data_mask(level0_index, level1_index))
+                );
+                let start_index = data_block_start_index::<Conf>(level0_index, level1_index);
+                // Ops with "full" hierarchy raise out of range blocks too.
+                if !Self::is_in_range(start_index){
+                    return Break(());
+                }
+                this.insert_datablock(DataBlock{ start_index, bit_block });
+                Continue(())
+            })
+        });
+        this
+    }
+    
+    /// Dense bitfield: bit `index % 64` of word `index / 64` is set for each index.
+    /// 
+    /// Length is `ceil((last + 1) / 64)`. Zero data blocks in between are filled
+    /// with zeros, non-empty ones are copied.
+    pub fn to_raw_words(&self) -> Vec<u64> {
+        let mut words = Vec::new();
+        for block in DefaultBlockIterator::new(self){
+            let block_words = block.bit_block.as_array();
+            let offset = block.start_index / 64;
+            words.resize(offset, 0);
+            words.extend_from_slice(block_words);
+        }
+        
+        // Trim last block trailing zeros.
+        let len = words.iter().rposition(|w| *w != 0).map_or(0, |i| i + 1);
+        words.truncate(len);
+        words
+    }
+    
+    /// Reverse of [to_raw_words()]. Only non-zero data blocks are inserted.
+    /// 
+    /// # Safety
+    /// 
+    /// Will panic, if any set bit is out of range.
+    /// 
+    /// [to_raw_words()]: Self::to_raw_words
+    pub fn from_raw_words(words: &[u64]) -> Self {
+        let mut this = Self::default();
+        let block_size = Conf::DataBitBlock::size();
+        for (i, chunk) in words.chunks(block_size / 64).enumerate() {
+            if chunk.iter().all(|w| *w == 0) {
+                continue;
+            }
+            let mut bit_block = Conf::DataBitBlock::zero();
+            bit_block.as_array_mut()[..chunk.len()].copy_from_slice(chunk);
+            this.insert_datablock(DataBlock{ start_index: i * block_size, bit_block });
+        }
+        this
+    }
+    
+    /// Word by word copy of data blocks. Length is `last + 1`.
+    #[cfg(feature = "fixedbitset")]
+    pub fn to_fixedbitset(&self) -> fixedbitset::FixedBitSet {
+        let len = crate::bitset_interface::bitset_last(self).map_or(0, |last| last + 1);
+        let mut fixed = fixedbitset::FixedBitSet::with_capacity(len);
+        let fixed_blocks = fixed.as_mut_slice();
+        for block in DefaultBlockIterator::new(self){
+            let first_word = block.start_index / 64;
+            for (i, &word) in block.bit_block.as_array().iter().enumerate(){
+                for j in 0..FIXED_BLOCKS_PER_WORD {
+                    // Words past `last` are zero. 
+                    if let Some(fixed_block) = fixed_blocks.get_mut((first_word + i) * FIXED_BLOCKS_PER_WORD + j){
+                        *fixed_block = (word >> (j * FIXED_BLOCK_BITS)) as fixedbitset::Block;
                     }
                 }
-            //}
-            existed
+            }
+        }
+        fixed
+    }
+    
+    /// Word by word copy of non-zero data blocks.
+    #[cfg(feature = "fixedbitset")]
+    pub fn from_fixedbitset(fixed: &fixedbitset::FixedBitSet) -> Self {
+        let mut this = Self::default();
+        let block_size = Conf::DataBitBlock::size();
+        for (i, chunk) in fixed.as_slice().chunks(block_size / FIXED_BLOCK_BITS).enumerate() {
+            if chunk.iter().all(|b| *b == 0) {
+                continue;
+            }
+            let mut bit_block = Conf::DataBitBlock::zero();
+            let words = bit_block.as_array_mut();
+            for (word, fixed_word) in words.iter_mut().zip(chunk.chunks(FIXED_BLOCKS_PER_WORD)){
+                for (j, &fixed_block) in fixed_word.iter().enumerate(){
+                    *word |= (fixed_block as u64) << (j * FIXED_BLOCK_BITS);
+                }
+            }
+            this.insert_datablock(DataBlock{ start_index: i * block_size, bit_block });
+        }
+        this
+    }
+    
+    /// Indices `< index` and indices `>= index`.
+    /// 
+    /// One pass over data blocks - only the block containing `index` is split.
+    pub fn split_at(&self, index: usize) -> (Self, Self) {
+        let mut left  = Self::default();
+        let mut right = Self::default();
+        let block_size = Conf::DataBitBlock::size();
+        for block in DefaultBlockIterator::new(self){
+            if block.start_index + block_size <= index {
+                left.insert_datablock(block);
+            } else if block.start_index >= index {
+                right.insert_datablock(block);
+            } else {
+                let mask: Conf::DataBitBlock = first_n_ones(index - block.start_index);
+                left.insert_datablock(DataBlock{ 
+                    start_index: block.start_index, 
+                    bit_block: block.bit_block & mask 
+                });
+                right.insert_datablock(DataBlock{ 
+                    start_index: block.start_index, 
+                    bit_block: block.bit_block ^ (block.bit_block & mask)
+                });
+            }
+        }
+        (left, right)
+    }
+    
+    /// `block` bits are XOR-ed into data block.
+    /// 
+    /// # Safety
+    /// 
+    /// Will panic, if `block.start_index` is out of range.
+    pub fn toggle_datablock(&mut self, block: DataBlock<Conf::DataBitBlock>){
+        assert!(Self::is_in_range(block.start_index), "{} index out of range!", block.start_index);
+        if block.is_empty(){
+            return;
+        }
+        self.generation += 1;
+        
+        let (level0_index, level1_index, _) = Self::level_indices(block.start_index);
+        unsafe{
+            let data_block_index = self.get_or_insert_data_block(level0_index, level1_index);
+            let data_block = self.data.blocks_mut().get_unchecked_mut(data_block_index);
+            let mask = *data_block.mask() ^ block.bit_block; 
+            *data_block.mask_mut() = mask;
+            if mask.is_zero(){
+                let level1_block_index = self.level0.get_or_zero(level0_index).as_usize();
+                self.remove_empty_data_block(
+                    level0_index, level1_index, 
+                    level1_block_index, data_block_index
+                );
+            }
+        }
+    }    
+    
+    /// In-place union.
+    pub fn union_update<S>(&mut self, other: S)
+    where
+        S: LevelMasksIterExt<Conf = Conf>
+    {
+        DefaultBlockIterator::new(other).for_each(|block|
+            self.insert_datablock(block)
+        );
+    }
+    
+    /// In-place intersection.
+    pub fn intersection_update<S>(&mut self, other: S)
+    where
+        S: LevelMasksIterExt<Conf = Conf>
+    {
+        self.update_data_blocks_with(other, |l, r| l & r);
+    }
+    
+    /// In-place difference.
+    pub fn difference_update<S>(&mut self, other: S)
+    where
+        S: LevelMasksIterExt<Conf = Conf>
+    {
+        self.update_data_blocks_with(other, |l, r| l & (l ^ r));
+    }
+    
+    /// Words of existing data block, starting at `start_index`.
+    /// 
+    /// # Safety
+    /// 
+    /// Data block must exist.
+    #[inline]
+    pub unsafe fn data_block_words_unchecked(&self, start_index: usize) -> &[u64] {
+        let (level0_index, level1_index, _) = Self::level_indices(start_index);
+        let (_, data_block_index) = self.get_block_indices(level0_index, level1_index)
+            .unwrap_unchecked();
+        self.data.blocks().get_unchecked(data_block_index).mask().as_array()
+    }
+    
+    /// `f(start_index, words)` for each data block. 
+    /// Data blocks that became empty are removed.
+    pub fn for_each_block_slice_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [u64])
+    {
+        self.update_data_blocks(|start_index, mut mask|{
+            f(start_index, mask.as_array_mut());
+            mask
+        });
+    }
+    
+    /// Retains only elements for which `f` returns true.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize) -> bool
+    {
+        self.update_data_blocks(|start_index, mask|{
+            let mut new_mask = mask;
+            let _ = mask.traverse_bits(|index|{
+                if !f(start_index + index){
+                    new_mask.set_bit::<false>(index);
+                }
+                Continue(())
+            });
+            new_mask
+        });
+    }
+    
+    /// Retains only elements within `range`.
+    /// 
+    /// Data blocks, fully inside `range`, are kept as is. 
+    /// Blocks outside are removed, edge blocks are masked.
+    #[inline]
+    pub fn retain_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        self.intersection_update(RangeBitSet::new(range));
+    }
+    
+    /// Flips all indices in `range`.
+    /// 
+    /// Each data block of `range` is XOR-ed as a whole - fully covered 
+    /// blocks with all ones, edge blocks with partial masks.
+    #[inline]
+    pub fn xor_assign_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        self.symmetric_difference_update(RangeBitSet::new(range));
+    }
+    
+    /// In-place symmetric difference.
+    pub fn symmetric_difference_update<S>(&mut self, other: S)
+    where
+        S: LevelMasksIterExt<Conf = Conf>
+    {
+        DefaultBlockIterator::new(other).for_each(|block|
+            self.toggle_datablock(block)
+        );
+    }
+    
+    /// Removes and returns the smallest element.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<usize> {
+        self.pop_edge::<false>()
+    }
+    
+    /// Removes and returns the largest element.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<usize> {
+        self.pop_edge::<true>()
+    }
+    
+    /// Descends by lowest (or highest, if `REV`) raised bits, 
+    /// and removes found element in place.
+    #[inline]
+    fn pop_edge<const REV: bool>(&mut self) -> Option<usize> {
+        let level0_index = edge_bit::<_, REV>(self.level0.mask())?;
+        self.generation += 1;
+        unsafe{
+            let level1_block_index = self.level0.get_or_zero(level0_index).as_usize();
+            let level1_block = self.level1.blocks().get_unchecked(level1_block_index);
+            let level1_index = edge_bit::<_, REV>(level1_block.mask()).unwrap_unchecked();
+            
+            let data_block_index = level1_block.get_or_zero(level1_index).as_usize();
+            let data_block = self.data.blocks_mut().get_unchecked_mut(data_block_index);
+            let data_index = edge_bit::<_, REV>(data_block.mask()).unwrap_unchecked();
+            
+            data_block.mask_mut().set_bit::<false>(data_index);
+            if data_block.is_empty(){
+                self.remove_empty_data_block(
+                    level0_index, level1_index, 
+                    level1_block_index, data_block_index
+                );
+            }
+            
+            Some(data_block_start_index::<Conf>(level0_index, level1_index) + data_index)
         }
     }
 }
 
+/// Lowest (or highest, if `REV`) raised bit.
+#[inline]
+fn edge_bit<B: BitBlock, const REV: bool>(block: &B) -> Option<usize> {
+    let mut result = None;
+    let f = |index|{
+        result = Some(index);
+        Break(())
+    };
+    let _ = if REV {
+        block.traverse_bits_rev(f)
+    } else {
+        block.traverse_bits(f)
+    };
+    result
+}
+
 impl<Conf, Level0Block, Level1Block, LevelDataBlock> BitSetBase 
 for 
     RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
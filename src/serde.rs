@@ -0,0 +1,370 @@
+//! Serialized as a sequence of non-empty data blocks. 
+//! Each data block is a tuple of `start_index` followed by
+//! bitblock's `u64`s.
+//! 
+//! For `_64bit` config, `[1, 3, 100]` is `[[0, 10], [64, 68719476736]]` in JSON.
+//! 
+//! [IndexListFormat] serializes as a plain sorted sequence of indices instead.
+
+use core::fmt;
+use core::marker::PhantomData;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use ::serde::de::{Error, SeqAccess, Visitor};
+use ::serde::ser::{SerializeSeq, SerializeTuple};
+use crate::{BitBlock, BitSetBase, BitSetInterface, DataBlock};
+use crate::config::{Config, DefaultBlockIterator, max_capacity};
+use crate::iter::{BlockCursor, IndexCursor};
+use crate::level::IBlock;
+use crate::raw::RawBitSet;
+
+struct SerializeDataBlock<'a, Block>(&'a DataBlock<Block>);
+
+impl<'a, Block: BitBlock> Serialize for SerializeDataBlock<'a, Block>{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let words = self.0.bit_block.as_array();
+        let mut tuple = serializer.serialize_tuple(1 + words.len())?;
+        tuple.serialize_element(&self.0.start_index)?;
+        for word in words {
+            tuple.serialize_element(word)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<Conf, Level0Block, Level1Block, LevelDataBlock> Serialize 
+for 
+    RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock<Mask = Conf::Level0BitBlock>,
+    Level1Block: IBlock<Mask = Conf::Level1BitBlock>,
+    LevelDataBlock: IBlock<Mask = Conf::DataBitBlock>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // All blocks are non-empty, since hierarchy is trusted.
+        let len = DefaultBlockIterator::new(self).count();
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for block in DefaultBlockIterator::new(self) {
+            seq.serialize_element(&SerializeDataBlock(&block))?;
+        }
+        seq.end()
+    }
+}
+
+struct DataBlockVisitor<Block>(PhantomData<Block>);
+
+impl<'de, Block: BitBlock> Visitor<'de> for DataBlockVisitor<Block>{
+    type Value = DataBlock<Block>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "data block tuple of start index and {} u64s", Block::size() / 64)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let start_index: usize = seq.next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let mut bit_block = Block::zero();
+        for (i, word) in bit_block.as_array_mut().iter_mut().enumerate() {
+            *word = seq.next_element()?
+                .ok_or_else(|| Error::invalid_length(i + 1, &self))?;
+        }
+        Ok(DataBlock{start_index, bit_block})
+    }
+}
+
+struct DeserializeDataBlock<Block>(DataBlock<Block>);
+
+impl<'de, Block: BitBlock> Deserialize<'de> for DeserializeDataBlock<Block>{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let len = 1 + Block::size() / 64;
+        deserializer.deserialize_tuple(len, DataBlockVisitor(PhantomData))
+            .map(DeserializeDataBlock)
+    }
+}
+
+struct RawBitSetVisitor<T>(PhantomData<T>);
+
+impl<'de, Conf, Level0Block, Level1Block, LevelDataBlock> Visitor<'de> 
+for 
+    RawBitSetVisitor<RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>>
+where
+    Conf: Config,
+    Level0Block: IBlock<Mask = Conf::Level0BitBlock>,
+    Level1Block: IBlock<Mask = Conf::Level1BitBlock>,
+    LevelDataBlock: IBlock<Mask = Conf::DataBitBlock>
+{
+    type Value = RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("sequence of data blocks")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bitset = Self::Value::default();
+        while let Some(DeserializeDataBlock(block)) = seq.next_element()? {
+            if block.start_index % Conf::DataBitBlock::size() != 0 {
+                return Err(Error::custom(format_args!(
+                    "data block start index {} is not aligned to data block size", 
+                    block.start_index
+                )));
+            }
+            if block.start_index >= Self::Value::max_capacity() {
+                return Err(Error::custom(format_args!(
+                    "data block start index {} out of range", 
+                    block.start_index
+                )));
+            }
+            bitset.insert_datablock(block);
+        }
+        Ok(bitset)
+    }
+}
+
+impl<'de, Conf, Level0Block, Level1Block, LevelDataBlock> Deserialize<'de> 
+for 
+    RawBitSet<Conf, Level0Block, Level1Block, LevelDataBlock>
+where
+    Conf: Config,
+    Level0Block: IBlock<Mask = Conf::Level0BitBlock>,
+    Level1Block: IBlock<Mask = Conf::Level1BitBlock>,
+    LevelDataBlock: IBlock<Mask = Conf::DataBitBlock>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(RawBitSetVisitor(PhantomData))
+    }
+}
+
+/// Serde adapter, that (de)serializes bitset as a sorted sequence of indices.
+/// 
+/// `{1, 5, 100}` is `[1, 5, 100]` in JSON - readable by any language 
+/// without knowledge of bitset internals.
+/// 
+/// Default [BitSet]/[SmallBitSet] format stores data blocks, and is
+/// much denser for contiguous data: each block of `_64bit` config 
+/// holds up to 64 indices in 2 numbers. Index list wins only for very sparse
+/// sets, where each data block contains one-two indices.
+/// 
+/// Any bitset, including lazy ones, can be serialized. Deserialization is 
+/// implemented for bitsets that can be extended - [BitSet] and [SmallBitSet].
+/// Input does not have to be sorted, but all indices must be within
+/// [max_capacity()].
+/// 
+/// ```
+/// # use hi_sparse_bitset::{BitSet, IndexListFormat};
+/// # use hi_sparse_bitset::config::_64bit;
+/// let set: BitSet<_64bit> = [1, 5, 100].into();
+/// let json = serde_json::to_string(&IndexListFormat(&set)).unwrap();
+/// assert_eq!(json, "[1,5,100]");
+/// 
+/// let IndexListFormat(set2): IndexListFormat<BitSet<_64bit>> 
+///     = serde_json::from_str(&json).unwrap();
+/// assert_eq!(set, set2);
+/// ```
+/// 
+/// [BitSet]: crate::BitSet
+/// [SmallBitSet]: crate::SmallBitSet
+/// [max_capacity()]: crate::config::max_capacity
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IndexListFormat<T>(pub T);
+
+impl<T> Serialize for IndexListFormat<T>
+where
+    T: BitSetInterface
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.exact_len()))?;
+        for index in self.0.iter() {
+            seq.serialize_element(&index)?;
+        }
+        seq.end()
+    }
+}
+
+struct IndexListVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for IndexListVisitor<T>
+where
+    T: BitSetBase + Default + Extend<usize>
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("sequence of indices")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bitset = T::default();
+        while let Some(index) = seq.next_element::<usize>()? {
+            if index >= max_capacity::<T::Conf>() {
+                return Err(Error::custom(format_args!("index {} out of range", index)));
+            }
+            bitset.extend(core::iter::once(index));
+        }
+        Ok(bitset)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for IndexListFormat<T>
+where
+    T: BitSetBase + Default + Extend<usize>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(IndexListVisitor(PhantomData)).map(IndexListFormat)
+    }
+}
+
+/// Serialized as `(level0_index, level1_next_index)` tuple.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<Conf: Config> Serialize for BlockCursor<Conf>{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.level0_index, self.level1_next_index).serialize(serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, Conf: Config> Deserialize<'de> for BlockCursor<Conf>{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (level0_index, level1_next_index) = <(u16, u16)>::deserialize(deserializer)?;
+        let cursor = BlockCursor{ level0_index, level1_next_index, phantom: PhantomData };
+        if !cursor.is_valid() {
+            return Err(Error::custom("cursor is out of Config hierarchy range"));
+        }
+        Ok(cursor)
+    }
+}
+
+/// Serialized as `(level0_index, level1_next_index, data_next_index)` tuple.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<Conf: Config> Serialize for IndexCursor<Conf>{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            self.block_cursor.level0_index, 
+            self.block_cursor.level1_next_index,
+            self.data_next_index
+        ).serialize(serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, Conf: Config> Deserialize<'de> for IndexCursor<Conf>{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (level0_index, level1_next_index, data_next_index) = 
+            <(u16, u16, u32)>::deserialize(deserializer)?;
+        let cursor = IndexCursor{ 
+            block_cursor: BlockCursor{ level0_index, level1_next_index, phantom: PhantomData }, 
+            data_next_index
+        };
+        if !cursor.is_valid() {
+            return Err(Error::custom("cursor is out of Config hierarchy range"));
+        }
+        Ok(cursor)
+    }
+}
+
+#[cfg(test)]
+mod test{
+    use std::fs::File;
+    use std::io::{BufReader, BufWriter, Write};
+    use crate::{BitSet, SmallBitSet};
+    use crate::config::_64bit;
+
+    #[test]
+    fn json_format_test(){
+        let set: BitSet<_64bit> = [1, 3, 100].into();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "[[0,10],[64,68719476736]]");
+        
+        let set2: BitSet<_64bit> = serde_json::from_str(&json).unwrap();
+        assert_eq!(set, set2);
+    }
+    
+    #[test]
+    fn index_list_test(){
+        use crate::{IndexListFormat, apply, ops::And};
+        
+        let set: BitSet<_64bit> = [1, 5, 100, 20_000].into();
+        let json = serde_json::to_string(&IndexListFormat(&set)).unwrap();
+        assert_eq!(json, "[1,5,100,20000]");
+        
+        let IndexListFormat(set2): IndexListFormat<BitSet<_64bit>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(set, set2);
+        let IndexListFormat(small_set): IndexListFormat<SmallBitSet<_64bit>> 
+            = serde_json::from_str("[100, 1, 20000, 5, 5]").unwrap();
+        assert!(small_set.iter().eq(set.iter()));
+        
+        // lazy
+        let other: BitSet<_64bit> = [5, 20_000, 30_000].into();
+        let json = serde_json::to_string(&IndexListFormat(apply(And, &set, &other))).unwrap();
+        assert_eq!(json, "[5,20000]");
+        
+        // out of range
+        assert!(serde_json::from_str::<IndexListFormat<BitSet<_64bit>>>("[262144]").is_err());
+        assert!(serde_json::from_str::<IndexListFormat<BitSet<_64bit>>>("[-1]").is_err());
+    }
+    
+    #[test]
+    fn json_invalid_test(){
+        // unaligned
+        assert!(serde_json::from_str::<BitSet<_64bit>>("[[1,10]]").is_err());
+        // out of range
+        assert!(serde_json::from_str::<BitSet<_64bit>>("[[262144,10]]").is_err());
+        // wrong block size
+        assert!(serde_json::from_str::<BitSet<_64bit>>("[[0,10,1]]").is_err());
+        assert!(serde_json::from_str::<BitSet<_64bit>>("[[0]]").is_err());
+    }
+    
+    /// `from_reader` can't deserialize borrowed data.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn json_from_reader_test(){
+        fn test<T>(name: &str) 
+        where
+            T: From<[usize; 6]> + Eq + core::fmt::Debug + serde::Serialize + serde::de::DeserializeOwned
+        {
+            let set: T = [1, 3, 100, 1000, 20000, 20001].into();
+            
+            let path = std::env::temp_dir().join(
+                format!("hi_sparse_bitset_{}_{}.json", name, std::process::id())
+            );
+            {
+                let mut writer = BufWriter::new(File::create(&path).unwrap());
+                serde_json::to_writer(&mut writer, &set).unwrap();
+                writer.flush().unwrap();
+            }
+            let set2: T = {
+                let reader = BufReader::new(File::open(&path).unwrap());
+                serde_json::from_reader(reader).unwrap()
+            };
+            std::fs::remove_file(&path).unwrap();
+            
+            assert_eq!(set, set2);
+        }
+        test::<BitSet<_64bit>>("bitset");
+        test::<SmallBitSet<_64bit>>("small_bitset");
+    }
+    
+    #[test]
+    fn cursor_json_test(){
+        use crate::iter::{BlockCursor, IndexCursor};
+        use crate::config::_128bit;
+        
+        let set: BitSet<_64bit> = (0..10_000).collect();
+        let mut iter = set.iter();
+        iter.nth(5000);
+        let json = serde_json::to_string(&iter.cursor()).unwrap();
+        
+        let cursor: IndexCursor<_64bit> = serde_json::from_str(&json).unwrap();
+        assert_eq!(set.iter().move_to(cursor).next(), Some(5001));
+        
+        let json = serde_json::to_string(&BlockCursor::<_64bit>::end()).unwrap();
+        assert_eq!(json, "[64,64]");
+        let cursor: BlockCursor<_64bit> = serde_json::from_str(&json).unwrap();
+        assert!(cursor == BlockCursor::end());
+        
+        // _128bit cursor does not fit _64bit hierarchy
+        let json = serde_json::to_string(&IndexCursor::<_128bit>::end()).unwrap();
+        assert!(serde_json::from_str::<IndexCursor<_64bit>>(&json).is_err());
+        assert!(serde_json::from_str::<BlockCursor<_64bit>>("[65,0]").is_err());
+    }
+}
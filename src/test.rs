@@ -29,6 +29,8 @@ cfg_if::cfg_if! {
         type Conf = config::_128bit<DefaultCache>;
     } else if #[cfg(hisparsebitset_test_256)] {
         type Conf = config::_256bit<DefaultCache>;
+    } else if #[cfg(hisparsebitset_test_512)] {
+        type Conf = config::_512bit<DefaultCache>;
     } else {
         type Conf = config::_128bit<DefaultCache>;
     }
@@ -46,6 +48,18 @@ cfg_if::cfg_if! {
 }
 type HiSparseBitset = BitSet<Conf>;
 
+/// Two sets, with `n` random indices from `0..max` inserted into each.
+fn random_sets(n: usize, max: usize) -> (HiSparseBitset, HiSparseBitset) {
+    let mut rng = rand::thread_rng();
+    let mut set1 = HiSparseBitset::new();
+    let mut set2 = HiSparseBitset::new();
+    for _ in 0..n {
+        set1.insert(rng.gen_range(0..max));
+        set2.insert(rng.gen_range(0..max));
+    }
+    (set1, set2)
+}
+
 #[test]
 fn level_indices_test(){
     type Conf = config::_128bit;
@@ -1027,4 +1041,2080 @@ fn is_empty_non_trusted_test(){
     let intersection = &bm0 & &bm1;
     dbg!(&intersection);
     assert!(!intersection.is_empty());    
-}
\ No newline at end of file
+}
+
+/// [is_empty_non_trusted_test] for all operations, with empty data blocks
+/// under raised hierarchy bits.
+#[test]
+fn is_empty_non_trusted_ops_test(){
+    use crate::config::*;
+    
+    let bm0: BitSet<_64bit> = [0, 1, 512, 800].into();
+    let bm1: BitSet<_64bit> = [1, 2, 511, 513, 800].into();
+    let empty: BitSet<_64bit> = BitSet::new();
+    
+    fn check<S: BitSetInterface>(set: S, expect_empty: bool){
+        assert_eq!(set.is_empty(), expect_empty);
+        assert_eq!(set.is_empty(), set.iter().next().is_none());
+    }
+    
+    check(&bm0 & &bm1, false);
+    check(&bm0 | &bm1, false);
+    check(&bm0 ^ &bm1, false);
+    check(&bm0 - &bm1, false);
+    
+    // Same data blocks, all bits cancel out.
+    check(&bm0 & &empty, true);
+    check(&bm0 ^ &bm0, true);
+    check(&bm0 - &bm0, true);
+    check(&empty | &empty, true);
+    
+    // Overlapping data blocks, no common bits.
+    let bm2: BitSet<_64bit> = [3, 514, 801].into();
+    check(&bm0 & &bm2, true);
+    check(&(&bm0 & &bm2) | &(&bm1 & &bm2), true);
+    check(&(&bm0 | &bm2) - &(&bm0 | &bm2), true);
+}
+#[test]
+fn ord_test(){
+    use std::collections::BTreeSet;
+    
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let mut sets: Vec<Vec<usize>> = Vec::new(); 
+        for _ in 0..10 {
+            let len = rng.gen_range(0..10);
+            let range = if rng.gen_bool(0.5) { 300 } else { 100_000 };  
+            sets.push((0..len).map(|_| rng.gen_range(0..range)).collect());
+        }
+        // common prefix
+        sets.push(vec![1, 5, 7]);
+        sets.push(vec![1, 5, 7, 8]);
+        sets.push(vec![1, 5, 7, 90000]);
+        sets.push(vec![1, 5]);
+        
+        let hash_sets: Vec<BTreeSet<usize>> = 
+            sets.iter().map(|s| s.iter().copied().collect()).collect();
+        let hi_sets: Vec<HiSparseBitset> = 
+            sets.iter().map(|s| s.iter().copied().collect()).collect();
+        
+        for (l, hl) in zip(&hash_sets, &hi_sets) {
+            for (r, hr) in zip(&hash_sets, &hi_sets) {
+                assert_eq!(hl.cmp(hr), l.cmp(r));
+                assert_eq!(hl.partial_cmp(hr), l.partial_cmp(r));
+                
+                // non-trusted hierarchy
+                let l_minus_r: BTreeSet<usize> = l.difference(r).copied().collect();
+                let hl_minus_hr = hl - hr;
+                assert_eq!(hl_minus_hr.cmp(&hl_minus_hr), std::cmp::Ordering::Equal);
+                assert_eq!(hl_minus_hr.partial_cmp(hr), Some(l_minus_r.cmp(r)));
+                assert_eq!(hr.partial_cmp(&hl_minus_hr), Some(r.cmp(&l_minus_r)));
+            }
+        }
+    }
+}
+
+#[test]
+fn ord_btreemap_key_test(){
+    use std::collections::BTreeMap;
+    
+    let mut map = BTreeMap::new();
+    map.insert(HiSparseBitset::from([1, 2, 3]), 0);
+    map.insert(HiSparseBitset::from([1, 2]), 1);
+    map.insert(HiSparseBitset::from([40000]), 2);
+    map.insert(HiSparseBitset::new(), 3);
+    map.insert(HiSparseBitset::from([1, 2, 3]), 4);
+    
+    assert_eq!(map.len(), 4);
+    assert_equal(map.values().copied(), [3, 1, 4, 2]);
+}
+
+#[test]
+fn hash_test(){
+    use std::hash::{Hash, Hasher};
+    use std::collections::hash_map::DefaultHasher;
+    
+    fn hash_of(v: impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+    
+    let set1 = HiSparseBitset::from([10, 64000, 10000]);
+    let set2 = HiSparseBitset::from([64000]);
+    let set3 = HiSparseBitset::from([10, 10000]);
+    let mut set4 = HiSparseBitset::from([10, 10000, 20000]);
+    set4.remove(20000);
+    
+    assert_eq!(hash_of(&set3), hash_of(&set4));
+    // non-trusted hierarchy
+    assert_eq!(hash_of(&set3), hash_of(&set1 - &set2));
+    assert_ne!(hash_of(&set3), hash_of(&set1));
+    
+    let mut hash_set = HashSet::new();
+    hash_set.insert(set1.clone());
+    hash_set.insert(set2.clone());
+    hash_set.insert(set3.clone());
+    hash_set.insert(set4.clone());
+    assert_eq!(hash_set.len(), 3);
+    assert!(hash_set.contains(&set4));
+}
+
+#[test]
+fn subset_disjoint_test(){
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let mut sets: Vec<HashSet<usize>> = Vec::new(); 
+        for _ in 0..6 {
+            let len = rng.gen_range(0..20);
+            let range = if rng.gen_bool(0.5) { 300 } else { 100_000 };  
+            sets.push((0..len).map(|_| rng.gen_range(0..range)).collect());
+        }
+        let union: HashSet<usize> = sets[0].union(&sets[1]).copied().collect();
+        sets.push(union);
+        
+        let hi_sets: Vec<HiSparseBitset> = 
+            sets.iter().map(|s| s.iter().copied().collect()).collect();
+        
+        for (l, hl) in zip(&sets, &hi_sets) {
+            for (r, hr) in zip(&sets, &hi_sets) {
+                assert_eq!(hl.is_subset(hr), l.is_subset(r));
+                assert_eq!(hl.is_superset(hr), l.is_superset(r));
+                assert_eq!(hl.is_disjoint(hr), l.is_disjoint(r));
+                
+                // non-trusted hierarchy
+                let l_and_r: HashSet<usize> = l.intersection(r).copied().collect();
+                let hl_and_hr = hl & hr;
+                assert_eq!(hl_and_hr.is_subset(hr), l_and_r.is_subset(r));
+                assert_eq!(hr.is_subset(&hl_and_hr), r.is_subset(&l_and_r));
+                assert_eq!(hl_and_hr.is_superset(hl), l_and_r.is_superset(l));
+                assert_eq!(hl_and_hr.is_disjoint(hr), l_and_r.is_disjoint(r));
+                assert_eq!(
+                    BitSetInterface::is_disjoint(&hl_and_hr, &hl_and_hr), 
+                    l_and_r.is_empty()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn inplace_ops_test(){
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let mut sets: Vec<HashSet<usize>> = Vec::new(); 
+        for _ in 0..4 {
+            let len = rng.gen_range(0..200);
+            let range = if rng.gen_bool(0.5) { 3000 } else { 100_000 };  
+            sets.push((0..len).map(|_| rng.gen_range(0..range)).collect());
+        }
+        let hi_sets: Vec<HiSparseBitset> = 
+            sets.iter().map(|s| s.iter().copied().collect()).collect();
+        
+        let check = |hi_set: &HiSparseBitset, set: &HashSet<usize>|{
+            let mut v: Vec<usize> = set.iter().copied().collect();
+            v.sort();
+            assert_equal(hi_set.iter(), v.iter().copied());
+            assert_eq!(hi_set.is_empty(), set.is_empty());
+            assert_eq!(hi_set, &v.into_iter().collect::<HiSparseBitset>());
+        };
+        
+        for (l, hl) in zip(&sets, &hi_sets) {
+            for (r, hr) in zip(&sets, &hi_sets) {
+                let mut s = hl.clone(); s &= hr;
+                check(&s, &(l & r));
+                let mut s = hl.clone(); s |= hr;
+                check(&s, &(l | r));
+                let mut s = hl.clone(); s ^= hr;
+                check(&s, &(l ^ r));
+                let mut s = hl.clone(); s -= hr;
+                check(&s, &(l - r));
+                
+                // non-trusted hierarchy
+                let r2 = &sets[0] - r;
+                let hr2 = &hi_sets[0] - hr;
+                let mut s = hl.clone(); s &= &hr2;
+                check(&s, &(l & &r2));
+                let mut s = hl.clone(); s |= &hr2;
+                check(&s, &(l | &r2));
+                let mut s = hl.clone(); s ^= &hr2;
+                check(&s, &(l ^ &r2));
+                let mut s = hl.clone(); s -= &hr2;
+                check(&s, &(l - &r2));
+            }
+        }
+        
+        // reduce
+        let mut s = hi_sets[0].clone(); 
+        s |= reduce(And, hi_sets[1..].iter()).unwrap();
+        let r = sets[1..].iter().fold(sets[1].clone(), |acc, s| &acc & s);
+        check(&s, &(&sets[0] | &r));
+    }
+}
+
+#[test]
+fn retain_test(){
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let len = rng.gen_range(0..1000);
+        let range = if rng.gen_bool(0.5) { 3000 } else { 100_000 };
+        let mut set: HashSet<usize> = (0..len).map(|_| rng.gen_range(0..range)).collect();
+        let mut hi_set: HiSparseBitset = set.iter().copied().collect();
+        
+        let m = rng.gen_range(1..5);
+        let f = |i: usize| i % m == 0 || (1000..2000).contains(&i);
+        set.retain(|&i| f(i));
+        hi_set.retain(f);
+        
+        let mut v: Vec<usize> = set.iter().copied().collect();
+        v.sort();
+        assert_equal(hi_set.iter(), v.iter().copied());
+        assert_eq!(hi_set.is_empty(), v.is_empty());
+    }
+    
+    let mut hi_set = HiSparseBitset::from([1, 2, 3, 10000, 10001]);
+    hi_set.retain(|_| false);
+    assert!(hi_set.is_empty());
+    hi_set.insert(20000);
+    assert_equal(hi_set.iter(), [20000]);
+}
+
+#[test]
+fn clear_test(){
+    let mut hi_set = HiSparseBitset::with_capacity_hint(10);
+    for _ in 0..3 {
+        assert!(hi_set.is_empty());
+        for i in (0..100_000).step_by(33) {
+            hi_set.insert(i);
+        }
+        assert_equal(hi_set.iter(), (0..100_000).step_by(33));
+        
+        hi_set.clear();
+        assert!(hi_set.is_empty());
+        assert_eq!(hi_set.iter().next(), None);
+        assert_eq!(hi_set, HiSparseBitset::new());
+        assert!(!hi_set.contains(33));
+    }
+}
+
+#[test]
+fn first_last_test(){
+    cfg_if::cfg_if! {
+    if #[cfg(miri)] {
+        const MAX_RANGE: usize = 10_000;
+        const REPEATS: usize = 2;
+    } else {
+        const MAX_RANGE: usize = 100_000;
+        const REPEATS: usize = 20;
+    }
+    }
+    
+    let mut rng = rand::thread_rng();
+    assert_eq!(HiSparseBitset::new().first(), None);
+    assert_eq!(HiSparseBitset::new().last(), None);
+    for _ in 0..REPEATS{
+        let (set1, set2) = random_sets(rng.gen_range(1..100), MAX_RANGE);
+        
+        assert_eq!(set1.first(), set1.iter().next());
+        assert_eq!(set1.last(), set1.iter().last());
+        
+        // Not TRUSTED_HIERARCHY
+        let and = &set1 & &set2;
+        assert_eq!(and.first(), and.iter().next());
+        assert_eq!(and.last(), and.iter().last());
+        let sub = &set1 - &set2;
+        assert_eq!(sub.first(), sub.iter().next());
+        assert_eq!(sub.last(), sub.iter().last());
+    }
+}
+
+#[test]
+fn config_max_capacity_test(){
+    const MAX: usize = HiSparseBitset::MAX_CAPACITY;
+    assert_eq!(MAX, HiSparseBitset::max_capacity());
+    assert_eq!(MAX, config::max_capacity::<Conf>());
+    
+    let mut set = HiSparseBitset::new();
+    set.insert(MAX - 1);
+    assert_equal(set.iter(), [MAX - 1]);
+}
+
+#[test]
+fn count_zeros_test(){
+    let max = HiSparseBitset::max_capacity();
+    let mut set = HiSparseBitset::new();
+    assert_eq!(set.count_trailing_zeros(), max);
+    assert_eq!(set.count_leading_zeros(), max);
+    
+    set.insert(0);
+    assert_eq!(set.count_trailing_zeros(), 0);
+    assert_eq!(set.count_leading_zeros(), max - 1);
+    
+    set.insert(max - 1);
+    set.remove(0);
+    assert_eq!(set.count_trailing_zeros(), max - 1);
+    assert_eq!(set.count_leading_zeros(), 0);
+    
+    let set: HiSparseBitset = [100, 5000].into();
+    assert_eq!(set.count_trailing_zeros(), 100);
+    assert_eq!(set.count_leading_zeros(), max - 5001);
+    
+    // Not TRUSTED_HIERARCHY
+    let other: HiSparseBitset = [100, 6000].into();
+    let sub = &set - &other;
+    assert_eq!(sub.count_trailing_zeros(), 5000);
+    assert_eq!(sub.count_leading_zeros(), max - 5001);
+}
+
+#[test]
+fn pop_first_last_test(){
+    let mut rng = rand::thread_rng();
+    let mut hi_set = HiSparseBitset::new();
+    let mut btree_set = std::collections::BTreeSet::new();
+    for _ in 0..1000{
+        let index = rng.gen_range(0..100_000);
+        hi_set.insert(index);
+        btree_set.insert(index);
+    }
+    
+    while !btree_set.is_empty() {
+        if rng.gen_bool(0.5) {
+            assert_eq!(hi_set.pop_first(), btree_set.pop_first());
+        } else {
+            assert_eq!(hi_set.pop_last(), btree_set.pop_last());
+        }
+    }
+    assert!(hi_set.is_empty());
+    assert_eq!(hi_set, HiSparseBitset::new());
+    assert_eq!(hi_set.pop_first(), None);
+    assert_eq!(hi_set.pop_last(), None);
+}
+
+#[test]
+fn index_iter_peek_test(){
+    let (set1, set2) = random_sets(1000, 100_000);
+    
+    let mut iter = set1.iter();
+    let mut expected = set1.iter();
+    loop{
+        let peeked = iter.peek();
+        assert_eq!(peeked, iter.peek());
+        
+        // cursor points to peeked index.
+        let cursor_iter = set1.iter().move_to(iter.cursor());
+        assert_eq!(cursor_iter.clone().next(), peeked);
+        
+        let next = iter.next();
+        assert_eq!(peeked, next);
+        assert_eq!(next, expected.next());
+        if next.is_none() {
+            break;
+        }
+    }
+    
+    // Not TRUSTED_HIERARCHY - may have empty data blocks.
+    let and = apply(And, &set1, &set2);
+    let mut iter = and.iter();
+    let mut peeked = Vec::new();
+    while let Some(index) = iter.peek() {
+        assert_eq!(iter.next(), Some(index));
+        peeked.push(index);
+    }
+    assert_equal(peeked, and.iter());
+    
+    // traverse after peek
+    let mut iter = set1.iter();
+    iter.peek();
+    let mut traversed = Vec::new();
+    let _ = iter.traverse(|i| { traversed.push(i); ControlFlow::Continue(()) });
+    assert_equal(traversed, set1.iter());
+}
+
+#[test]
+fn shrink_to_fit_test(){
+    let mut rng = rand::thread_rng();
+    let mut hi_set = HiSparseBitset::new();
+    let mut hash_set = HashSet::new();
+    for _ in 0..3{
+        for _ in 0..2000{
+            let index = rng.gen_range(0..200_000);
+            hi_set.insert(index);
+            hash_set.insert(index);
+        }
+        for _ in 0..1900{
+            let index = rng.gen_range(0..200_000);
+            hi_set.remove(index);
+            hash_set.remove(&index);
+        }
+        
+        hi_set.shrink_to_fit();
+        
+        let mut indices: Vec<_> = hash_set.iter().copied().collect();
+        indices.sort();
+        assert_equal(hi_set.iter(), indices.iter().copied());
+        for &index in &indices{
+            assert!(hi_set.contains(index));
+        }
+    }
+    
+    hi_set.clear();
+    hi_set.shrink_to_fit();
+    assert!(hi_set.is_empty());
+    hi_set.insert(100);
+    assert_equal(hi_set.iter(), [100]);
+}
+
+#[test]
+fn compact_test(){
+    let mut rng = rand::thread_rng();
+    let mut hi_set = HiSparseBitset::new();
+    let mut hash_set = HashSet::new();
+    for _ in 0..3{
+        for _ in 0..2000{
+            let index = rng.gen_range(0..200_000);
+            hi_set.insert(index);
+            hash_set.insert(index);
+        }
+        for _ in 0..1000{
+            let index = rng.gen_range(0..200_000);
+            hi_set.remove(index);
+            hash_set.remove(&index);
+        }
+        
+        let usage = hi_set.memory_usage();
+        hi_set.compact();
+        let compacted_usage = hi_set.memory_usage();
+        assert_eq!(compacted_usage.level1_allocated_capacity_bytes, usage.level1_allocated_capacity_bytes);
+        assert_eq!(compacted_usage.data_allocated_capacity_bytes, usage.data_allocated_capacity_bytes);
+        assert!(compacted_usage.data_blocks_bytes <= usage.data_blocks_bytes);
+        
+        let mut indices: Vec<_> = hash_set.iter().copied().collect();
+        indices.sort();
+        assert_equal(hi_set.iter(), indices.iter().copied());
+        for &index in &indices{
+            assert!(hi_set.contains(index));
+        }
+    }
+    
+    hi_set.clear();
+    hi_set.compact();
+    assert!(hi_set.is_empty());
+    hi_set.insert(100);
+    assert_equal(hi_set.iter(), [100]);
+}
+
+#[test]
+fn level_stats_test(){
+    let level0_size = <Conf as Config>::Level0BitBlock::size() as f64;
+    let level1_size = <Conf as Config>::Level1BitBlock::size() as f64;
+    let data_size = <Conf as Config>::DataBitBlock::size();
+    let level1_span = <Conf as Config>::Level1BitBlock::size() * data_size;
+    
+    let empty = HiSparseBitset::new().level_stats();
+    assert_eq!(empty, LevelStats::default());
+    
+    let mut set = HiSparseBitset::new();
+    set.insert(0);
+    set.insert(1);
+    set.insert(data_size);
+    set.insert(level1_span + 5);
+    let stats = set.level_stats();
+    assert_eq!(stats.level0_fill_fraction, 2.0 / level0_size);
+    assert_eq!(stats.level1_block_count, 2);
+    assert_eq!(stats.level1_fill_fractions, vec![2.0 / level1_size, 1.0 / level1_size]);
+    assert_eq!(stats.data_block_count, 3);
+    assert_eq!(stats.total_set_bits, 4);
+    assert_eq!(stats.avg_bits_per_data_block, 4.0 / 3.0);
+    
+    // Removed blocks are not counted.
+    set.remove(level1_span + 5);
+    let stats = set.level_stats();
+    assert_eq!(stats.level1_block_count, 1);
+    assert_eq!(stats.data_block_count, 2);
+    assert_eq!(stats.total_set_bits, 3);
+}
+
+#[test]
+fn population_count_by_level_test(){
+    let data_size = <Conf as Config>::DataBitBlock::size();
+    let level1_span = <Conf as Config>::Level1BitBlock::size() * data_size;
+    
+    assert_eq!(HiSparseBitset::new().population_count_by_level(), [0, 0, 0]);
+    
+    let mut set = HiSparseBitset::new();
+    set.insert(0);
+    set.insert(1);
+    set.insert(data_size);
+    set.insert(level1_span + 5);
+    assert_eq!(set.population_count_by_level(), [2, 3, 4]);
+    
+    set.remove(level1_span + 5);
+    assert_eq!(set.population_count_by_level(), [1, 2, 3]);
+    
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        set.insert(rng.gen_range(0..HiSparseBitset::max_capacity()));
+    }
+    let stats = set.level_stats();
+    assert_eq!(
+        set.population_count_by_level(),
+        [stats.level1_block_count, stats.data_block_count, set.iter().count()]
+    );
+}
+
+#[test]
+fn memory_usage_test(){
+    let mut hi_set = HiSparseBitset::new();
+    let empty_usage = hi_set.memory_usage();
+    
+    for i in 0..100_000{
+        hi_set.insert(i);
+    }
+    let full_usage = hi_set.memory_usage();
+    assert!(full_usage.data_blocks_bytes > empty_usage.data_blocks_bytes);
+    assert!(full_usage.level1_blocks_bytes > empty_usage.level1_blocks_bytes);
+    assert!(full_usage.data_allocated_capacity_bytes >= full_usage.data_blocks_bytes);
+    assert!(full_usage.level1_allocated_capacity_bytes >= full_usage.level1_blocks_bytes);
+    assert_eq!(full_usage.level0_bytes, empty_usage.level0_bytes);
+    
+    // Removed blocks are kept until shrink_to_fit.
+    for i in 100..100_000{
+        hi_set.remove(i);
+    }
+    assert_eq!(hi_set.memory_usage(), full_usage);
+    hi_set.shrink_to_fit();
+    let shrunk_usage = hi_set.memory_usage();
+    assert!(shrunk_usage.total() < full_usage.total());
+    assert_eq!(shrunk_usage.data_allocated_capacity_bytes, shrunk_usage.data_blocks_bytes);
+    
+    assert_eq!(hi_set.approximate_memory_usage(), shrunk_usage.total());
+    let other = hi_set.clone();
+    assert_eq!(
+        apply(And, &hi_set, &other).approximate_memory_usage(), 
+        shrunk_usage.total() * 2
+    );
+    assert_eq!(
+        reduce(Or, [&hi_set, &other, &hi_set].into_iter()).unwrap().approximate_memory_usage(), 
+        shrunk_usage.total() * 3
+    );
+}
+
+#[test]
+fn from_fn_test(){
+    let set = HiSparseBitset::from_fn(0..10_000, |i| i % 3 == 0);
+    assert_equal(set.iter(), (0..10_000).step_by(3));
+    
+    let set = HiSparseBitset::from_fn(100..=1000, |i| i % 7 != 0);
+    assert_equal(set.iter(), (100..=1000).filter(|i| i % 7 != 0));
+    
+    let set = HiSparseBitset::from_fn(10..10, |_| true);
+    assert!(set.is_empty());
+    
+    // Empty blocks are not allocated.
+    let set = HiSparseBitset::from_fn(..=100_000, |i| i == 5 || i == 100_000);
+    assert_equal(set.iter(), [5, 100_000]);
+    assert_eq!(set.block_iter().count(), 2);
+    
+    use core::ops::Bound;
+    let set = HiSparseBitset::from_fn((Bound::Excluded(usize::MAX), Bound::Unbounded), |_| true);
+    assert!(set.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn from_fn_overflow_test(){
+    HiSparseBitset::from_fn(..=usize::MAX, |_| true);
+}
+
+#[test]
+fn rev_iter_test(){
+    assert_eq!(HiSparseBitset::new().rev_iter().next(), None);
+    
+    let (set1, set2) = random_sets(1000, 100_000);
+    
+    let mut reversed: Vec<_> = set1.iter().collect();
+    reversed.reverse();
+    assert_equal(set1.rev_iter(), reversed);
+    
+    let mut reversed: Vec<_> = set1.block_iter().map(|block| block.start_index).collect();
+    reversed.reverse();
+    assert_equal(set1.rev_block_iter().map(|block| block.start_index), reversed);
+    
+    // Not TRUSTED_HIERARCHY
+    let and = apply(And, &set1, &set2);
+    let mut reversed: Vec<_> = and.iter().collect();
+    reversed.reverse();
+    assert_equal(and.rev_iter(), reversed);
+    
+    let reduced = reduce(Or, [&set1, &set2].into_iter()).unwrap();
+    let mut reversed: Vec<_> = reduced.iter().collect();
+    reversed.reverse();
+    assert_equal(reduced.rev_iter(), reversed);
+}
+
+#[test]
+fn iter_count_test(){
+    assert_eq!(HiSparseBitset::new().iter().count(), 0);
+    
+    let (set1, set2) = random_sets(1000, 100_000);
+    
+    let mut iter = set1.iter();
+    let mut len = iter.clone().fold(0, |n, _| n + 1);
+    assert_eq!(iter.clone().count(), len);
+    while let Some(_) = iter.next() {
+        len -= 1;
+        assert_eq!(iter.clone().count(), len);
+        
+        let (min, max) = iter.size_hint();
+        assert!(min <= len);
+        assert!(len <= max.unwrap());
+    }
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    
+    let mut block_iter = set1.block_iter();
+    let mut len = set1.block_iter().fold(0, |n, _| n + 1);
+    assert!(block_iter.size_hint().0 >= 1);
+    while block_iter.next().is_some() {
+        len -= 1;
+        let (min, max) = block_iter.size_hint();
+        assert!(min <= len);
+        assert!(len <= max.unwrap());
+    }
+    assert_eq!(block_iter.size_hint(), (0, Some(0)));
+    
+    // Not TRUSTED_HIERARCHY
+    let and = apply(And, &set1, &set2);
+    let mut iter = and.iter();
+    let mut len = iter.clone().fold(0, |n, _| n + 1);
+    assert_eq!(iter.clone().count(), len);
+    while iter.next().is_some() {
+        len -= 1;
+        assert_eq!(iter.clone().count(), len);
+        let (min, max) = iter.size_hint();
+        assert!(min <= len);
+        assert!(len <= max.unwrap());
+    }
+    let block_len = and.block_iter().fold(0, |n, _| n + 1);
+    let (min, max) = and.block_iter().size_hint();
+    assert!(min <= block_len);
+    assert!(block_len <= max.unwrap());
+    let reduced = reduce(And, [&set1, &set2].into_iter()).unwrap();
+    assert_eq!(reduced.iter().count(), reduced.iter().fold(0, |n, _| n + 1));
+}
+
+#[test]
+fn iter_nth_test(){
+    let mut rng = rand::thread_rng();
+    assert_eq!(HiSparseBitset::new().iter().nth(0), None);
+    
+    let (set1, set2) = random_sets(1000, 100_000);
+    
+    let indices: Vec<_> = set1.iter().collect();
+    for n in [0, 1, 2, 63, 64, 65, 500, indices.len()-1, indices.len(), indices.len()+10]{
+        assert_eq!(set1.iter().nth(n), indices.get(n).copied());
+    }
+    
+    // consecutive nth
+    let mut iter = set1.iter();
+    let mut expected = indices.iter().copied();
+    loop{
+        let n = rng.gen_range(0..100);
+        let v = iter.nth(n);
+        assert_eq!(v, expected.nth(n));
+        if v.is_none(){
+            break;
+        }
+    }
+    assert_eq!(iter.next(), None);
+    
+    // Not TRUSTED_HIERARCHY
+    let and = apply(And, &set1, &set2);
+    let indices: Vec<_> = and.iter().collect();
+    for n in 0..indices.len()+2{
+        assert_eq!(and.iter().nth(n), indices.get(n).copied());
+    }
+}
+
+#[test]
+fn data_block_count_test(){
+    let mut set = HiSparseBitset::new();
+    let block_size = <<Conf as Config>::DataBitBlock as BitBlock>::size();
+    
+    let block = set.block_iter().next();
+    assert!(block.is_none());
+    
+    set.insert(block_size + 1);
+    set.insert(block_size + 3);
+    set.insert(block_size * 2 - 1);
+    
+    let block = set.block_iter().next().unwrap();
+    assert_eq!(block.count_ones(), 3);
+    assert_eq!(block.count_zeros(), block_size - 3);
+    assert_eq!(block.first_index(), Some(block_size + 1));
+    assert_eq!(block.last_index(), Some(block_size * 2 - 1));
+    
+    let empty = DataBlock{ start_index: 0, bit_block: <<Conf as Config>::DataBitBlock as BitBlock>::zero() };
+    assert_eq!(empty.count_ones(), 0);
+    assert_eq!(empty.first_index(), None);
+    assert_eq!(empty.last_index(), None);
+}
+
+#[test]
+fn insert_datablock_test(){
+    let block_size = <Conf as Config>::DataBitBlock::size();
+    let mut set = HiSparseBitset::new();
+    set.insert(block_size * 3 + 2);
+    
+    let mut other = HiSparseBitset::new();
+    other.insert(block_size * 3);
+    other.insert(block_size * 3 + 7);
+    other.insert(block_size * 10 + 1);
+    for block in other.block_iter(){
+        set.insert_datablock(block);
+    }
+    assert_equal(set.iter(), [
+        block_size * 3, block_size * 3 + 2, block_size * 3 + 7, block_size * 10 + 1
+    ]);
+    
+    // empty block does not create hierarchy path
+    let generation = set.generation();
+    set.insert_datablock(DataBlock{ 
+        start_index: block_size * 20, 
+        bit_block: <<Conf as Config>::DataBitBlock as BitBlock>::zero() 
+    });
+    assert_eq!(set.generation(), generation);
+    assert!(set.block_iter().all(|block| block.start_index != block_size * 20));
+}
+
+#[test]
+fn remove_toggle_datablock_test(){
+    let block_size = <Conf as Config>::DataBitBlock::size();
+    let mut set: HiSparseBitset = [1, 5, block_size + 2, block_size * 5 + 1, block_size * 5 + 3].into();
+    
+    assert!(set.remove_datablock(3));
+    assert_equal(set.iter(), [block_size + 2, block_size * 5 + 1, block_size * 5 + 3]);
+    assert!(!set.remove_datablock(3));
+    assert!(!set.remove_datablock(block_size * 2));
+    assert!(!set.remove_datablock(usize::MAX));
+    assert!(set.remove_datablock(block_size + 2));
+    assert_eq!(set.block_iter().count(), 1);
+    
+    let mut mask = <<Conf as Config>::DataBitBlock as BitBlock>::zero();
+    mask.set_bit::<true>(1);
+    mask.set_bit::<true>(2);
+    let block = DataBlock{ start_index: block_size * 5, bit_block: mask };
+    set.toggle_datablock(block);
+    assert_equal(set.iter(), [block_size * 5 + 2, block_size * 5 + 3]);
+    set.toggle_datablock(DataBlock{ start_index: 0, bit_block: mask });
+    assert_equal(set.iter(), [1, 2, block_size * 5 + 2, block_size * 5 + 3]);
+    
+    // toggled to empty - block removed
+    set.toggle_datablock(DataBlock{ start_index: 0, bit_block: mask });
+    assert_eq!(set.block_iter().count(), 1);
+    set.remove_datablock(block_size * 5);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn blocks_as_slices_test(){
+    let block_size = <Conf as Config>::DataBitBlock::size();
+    let words_len = block_size / 64;
+    let mut set: HiSparseBitset = [1, 70 % block_size, block_size * 3 + 2, block_size * 3 + 5].into();
+    
+    let slices: Vec<(usize, Vec<u64>)> = set.iter_blocks_as_slices()
+        .map(|(start_index, words)| (start_index, words.to_vec()))
+        .collect();
+    let blocks: Vec<(usize, Vec<u64>)> = set.block_iter()
+        .map(|block| (block.start_index, block.bit_block.as_array().to_vec()))
+        .collect();
+    assert_eq!(slices, blocks);
+    assert!(slices.iter().all(|(_, words)| words.len() == words_len));
+    
+    // Clear first bit of each block. First block becomes empty.
+    set.remove(70 % block_size);
+    set.for_each_block_slice_mut(|start_index, words|{
+        assert!(start_index % block_size == 0);
+        let word = words.iter_mut().find(|w| **w != 0).unwrap();
+        *word &= *word - 1;
+    });
+    assert_equal(set.iter(), [block_size * 3 + 5]);
+    assert_eq!(set.iter_blocks_as_slices().count(), 1);
+    
+    set.for_each_block_slice_mut(|_, words| words.fill(0));
+    assert!(set.is_empty());
+    assert_eq!(set.iter_blocks_as_slices().next(), None);
+}
+
+#[test]
+fn from_iter_datablock_test(){
+    let mut rng = rand::thread_rng();
+    let mut set = HiSparseBitset::new();
+    for _ in 0..3000{
+        set.insert(rng.gen_range(0..100_000));
+    }
+    
+    let collected: HiSparseBitset = set.block_iter().collect();
+    assert_eq!(collected, set);
+    
+    let even: HiSparseBitset = set.block_iter()
+        .filter(|block| (block.start_index / <Conf as Config>::DataBitBlock::size()) % 2 == 0)
+        .collect();
+    assert_equal(even.iter(), set.iter().filter(|i| (i / <Conf as Config>::DataBitBlock::size()) % 2 == 0));
+    
+    // Same block twice
+    let block = set.block_iter().next().unwrap();
+    let twice: HiSparseBitset = [block.clone(), block.clone()].into_iter().collect();
+    assert_equal(twice.iter(), block.iter());
+}
+
+#[test]
+fn data_block_ops_test(){
+    let block_size = <<Conf as Config>::DataBitBlock as BitBlock>::size();
+    let set1: HiSparseBitset = [block_size, block_size + 1, block_size + 5].into();
+    let set2: HiSparseBitset = [block_size + 1, block_size + 7].into();
+    let block1 = set1.block_iter().next().unwrap();
+    let block2 = set2.block_iter().next().unwrap();
+    
+    assert_equal(block1.intersection(&block2).iter(), [block_size + 1]);
+    assert_equal(block1.union(&block2).iter(), [block_size, block_size + 1, block_size + 5, block_size + 7]);
+    assert_equal(block1.difference(&block2).iter(), [block_size, block_size + 5]);
+    assert_equal(block1.symmetric_difference(&block2).iter(), [block_size, block_size + 5, block_size + 7]);
+    assert_eq!(block1.intersection(&block2).start_index, block_size);
+    
+    assert!(block1.intersection(&block2).is_subset_of(&block1));
+    assert!(block1.is_subset_of(&block1.union(&block2)));
+    assert!(!block1.is_subset_of(&block2));
+    assert!(block1.difference(&block1).is_empty());
+    
+    let shifted = block1.shift_start(block_size * 3);
+    assert_equal(shifted.iter(), [block_size * 3, block_size * 3 + 1, block_size * 3 + 5]);
+    let mut set3 = HiSparseBitset::new();
+    set3.extend([shifted]);
+    assert_equal(set3.iter(), [block_size * 3, block_size * 3 + 1, block_size * 3 + 5]);
+}
+
+#[cfg(feature = "roaring")]
+#[test]
+fn roaring_test(){
+    use roaring::RoaringBitmap;
+    
+    let mut rng = rand::thread_rng();
+    let mut set = HiSparseBitset::new();
+    for _ in 0..1000{
+        set.insert(rng.gen_range(0..100_000));
+    }
+    
+    let roaring: RoaringBitmap = (&set).into();
+    assert_equal(roaring.iter().map(|i| i as usize), set.iter());
+    
+    let set2: HiSparseBitset = roaring.into();
+    assert_eq!(set, set2);
+    
+    let empty: RoaringBitmap = HiSparseBitset::new().into();
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "fixedbitset")]
+#[test]
+fn fixedbitset_test(){
+    use fixedbitset::FixedBitSet;
+    
+    let mut rng = rand::thread_rng();
+    let mut set = HiSparseBitset::new();
+    for _ in 0..1000{
+        set.insert(rng.gen_range(0..100_000));
+    }
+    
+    let fixed: FixedBitSet = (&set).into();
+    assert_eq!(fixed.len(), set.last().unwrap() + 1);
+    assert_equal(fixed.ones(), set.iter());
+    
+    let set2: HiSparseBitset = fixed.into();
+    assert_eq!(set, set2);
+    
+    let mut fixed = FixedBitSet::with_capacity(1000);
+    fixed.insert_range(100..301);
+    fixed.insert(999);
+    let set3: HiSparseBitset = (&fixed).into();
+    assert_equal(set3.iter(), (100..=300).chain([999]));
+    
+    let empty: FixedBitSet = HiSparseBitset::new().into();
+    assert_eq!(empty.len(), 0);
+    assert_eq!(HiSparseBitset::from(empty), HiSparseBitset::new());
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_test{
+    use ::proptest::prelude::*;
+    use crate::ArbitraryParams;
+    use super::*;
+    
+    proptest! {
+        #[test]
+        fn arbitrary_ops_test(s1: HiSparseBitset, s2: HiSparseBitset){
+            let h1: HashSet<usize> = s1.iter().collect();
+            let h2: HashSet<usize> = s2.iter().collect();
+            let mut and: Vec<_> = h1.intersection(&h2).copied().collect();
+            and.sort();
+            prop_assert_eq!((&s1 & &s2).iter().collect::<Vec<_>>(), and);
+            prop_assert!(s1.iter().all(|i| i <= ArbitraryParams::default().max_index));
+        }
+        
+        #[test]
+        fn arbitrary_params_test(s in any_with::<HiSparseBitset>(ArbitraryParams{ 
+            max_index: 100, density: 0.5, max_len: 20 
+        })){
+            prop_assert!(s.iter().count() <= 20);
+            prop_assert!(s.iter().all(|i| i <= 100));
+        }
+    }
+}
+
+#[test]
+fn raw_words_test(){
+    let mut rng = rand::thread_rng();
+    let mut set = HiSparseBitset::new();
+    assert!(set.to_raw_words().is_empty());
+    assert_eq!(HiSparseBitset::from_raw_words(&[]), set);
+    
+    for _ in 0..1000{
+        set.insert(rng.gen_range(0..100_000));
+    }
+    
+    let words = set.to_raw_words();
+    assert_eq!(words.len(), (set.last().unwrap() + 1).div_ceil(64));
+    for index in 0..words.len()*64 {
+        let bit = words[index / 64] & (1 << (index % 64)) != 0;
+        assert_eq!(bit, set.contains(index));
+    }
+    
+    let set2 = HiSparseBitset::from_raw_words(&words);
+    assert_eq!(set, set2);
+    
+    let set3 = HiSparseBitset::from_raw_words(&[0, 0, 0b101, 0]);
+    assert_equal(set3.iter(), [128, 130]);
+}
+
+#[test]
+fn reduce_count_test(){
+    let mut rng = rand::thread_rng();
+    let sets: Vec<HiSparseBitset> = (0..5).map(|_|
+        (0..2000).map(|_| rng.gen_range(0..10_000)).collect()
+    ).collect();
+    
+    fn check<Op: BitSetOp>(op: Op, sets: &[HiSparseBitset]){
+        let expected = reduce(op, sets.iter()).unwrap().iter().fold(0, |n, _| n + 1);
+        assert_eq!(reduce_count(op, sets.iter()), expected);
+    }
+    check(And, &sets);
+    check(Or, &sets);
+    check(Xor, &sets);
+    check(Sub, &sets);
+    
+    assert_eq!(reduce_count(And, sets[..0].iter()), 0);
+}
+
+#[test]
+fn reduce_w_state_test(){
+    let mut rng = rand::thread_rng();
+    let sets: Vec<HiSparseBitset> = (0..5).map(|_|
+        (0..2000).map(|_| rng.gen_range(0..10_000)).collect()
+    ).collect();
+    
+    fn check<Op: BitSetOp>(op: Op, sets: &[HiSparseBitset]){
+        let expected: Vec<usize> = reduce(op, sets.iter()).unwrap().iter().collect();
+        let (sum, indices) = reduce_w_state(op, sets.iter(), (0, Vec::new()), |(sum, indices), index|{
+            *sum += index;
+            indices.push(index);
+        });
+        assert_eq!(sum, expected.iter().sum::<usize>());
+        assert_eq!(indices, expected);
+    }
+    check(And, &sets);
+    check(Or, &sets);
+    check(Xor, &sets);
+    
+    assert_eq!(reduce_w_state(Or, sets[..0].iter(), 42, |n, _| *n += 1), 42);
+}
+
+#[test]
+fn reduce_any_test(){
+    let set1: HiSparseBitset = [1, 100, 5000].into();
+    let set2: HiSparseBitset = [2, 100, 6000].into();
+    let set3: HiSparseBitset = [3, 5000, 6000].into();
+    
+    assert!(reduce_any(And, [&set1, &set2].into_iter()));
+    assert!(!reduce_any(And, [&set1, &set2, &set3].into_iter()));
+    assert!(reduce_any(Or, [&set1, &set2, &set3].into_iter()));
+    assert!(!reduce_any(Sub, [&set1, &set1].into_iter()));
+    assert!(!reduce_any(Xor, [&set1, &set1].into_iter()));
+    assert!(reduce_any(Xor, [&set1, &set2].into_iter()));
+    assert!(!reduce_any(Or, std::iter::empty::<&HiSparseBitset>()));
+}
+
+#[test]
+fn reduce_first_test(){
+    let mut rng = rand::thread_rng();
+    let sets: Vec<HiSparseBitset> = (0..3).map(|_|
+        (0..2000).map(|_| rng.gen_range(0..10_000)).collect()
+    ).collect();
+    
+    fn check<Op: BitSetOp>(op: Op, sets: &[HiSparseBitset]){
+        let expected = reduce(op, sets.iter()).and_then(|r| r.iter().next());
+        assert_eq!(reduce_first(op, sets.iter()), expected);
+    }
+    check(And, &sets);
+    check(Or, &sets);
+    check(Xor, &sets);
+    check(Sub, &sets);
+    check(And, &sets[..0]);
+    
+    let set1: HiSparseBitset = [1, 100, 5000].into();
+    let set2: HiSparseBitset = [2, 6000].into();
+    assert_eq!(reduce_first(And, [&set1, &set2].into_iter()), None);
+}
+
+#[test]
+fn cursor_ord_test(){
+    type Conf = config::_64bit;
+    
+    assert!(BlockCursor::<Conf>::start().is_before(&BlockCursor::end()));
+    assert!(BlockCursor::<Conf>::from(100).is_before(&BlockCursor::from(5000)));
+    assert!(BlockCursor::<Conf>::from(100) == BlockCursor::from(127));
+    assert!(!BlockCursor::<Conf>::from(100).is_before(&BlockCursor::from(127)));
+    
+    assert!(IndexCursor::<Conf>::start().is_before(&IndexCursor::end()));
+    assert!(IndexCursor::<Conf>::from(100).is_before(&IndexCursor::from(101)));
+    assert!(IndexCursor::<Conf>::from(4000) < IndexCursor::from(5000));
+    assert!(IndexCursor::<Conf>::from(5000) <= IndexCursor::from(5000));
+    
+    let cursor = IndexCursor::<Conf>::from(100);
+    assert!(cursor.advance_by(0) == cursor);
+    assert!(cursor.advance_by(4000) == IndexCursor::from(4100));
+    assert!(!IndexCursor::end().is_before(&cursor.advance_by(usize::MAX)));
+    
+    // advance_by iteration
+    let set: BitSet<Conf> = (0..10_000).step_by(3).collect();
+    let start = IndexCursor::<Conf>::from(1000);
+    let end = start.advance_by(1000);
+    let chunk: Vec<_> = set.iter().move_to(start).take_while(|i| *i < 2000).collect();
+    assert_equal(chunk, (1000..2000).filter(|i| i % 3 == 0));
+    assert_eq!(set.iter().move_to(end).next(), Some(2001));
+}
+
+#[test]
+fn cursor_validate_for_test(){
+    type Set64<'a>  = &'a super::BitSet<config::_64bit>;
+    type Set128<'a> = &'a super::BitSet<config::_128bit>;
+    
+    assert!(BlockCursor::<config::_64bit>::end().validate_for::<Set128>());
+    assert!(IndexCursor::<config::_64bit>::end().validate_for::<Set128>());
+    assert!(BlockCursor::<config::_128bit>::start().validate_for::<Set64>());
+    assert!(!BlockCursor::<config::_128bit>::end().validate_for::<Set64>());
+    assert!(!IndexCursor::<config::_128bit>::end().validate_for::<Set64>());
+    assert!(IndexCursor::<config::_128bit>::end().validate_for::<Set128>());
+}
+
+#[test]
+fn cursor_rewind_by_test(){
+    type Conf = config::_64bit;
+    let set: BitSet<Conf> = (0..20_000).step_by(3).chain(200_000..200_010).collect();
+    
+    let mut iter = set.iter();
+    iter.nth(99);
+    let cursor = iter.cursor();
+    
+    // replay last 10 elements
+    let rewound = cursor.rewind_by(&set, 10);
+    let replay: Vec<_> = set.iter().move_to(rewound).take(10).collect();
+    assert_equal(replay, (270..300).step_by(3));
+    
+    assert!(cursor.rewind_by(&set, 0) == cursor);
+    assert!(cursor.rewind_by(&set, 100) == IndexCursor::from(0));
+    assert!(cursor.rewind_by(&set, 1000) == IndexCursor::start());
+    
+    // across empty blocks
+    let cursor = IndexCursor::<Conf>::from(200_005);
+    assert!(cursor.rewind_by(&set, 5) == IndexCursor::from(200_000));
+    assert!(cursor.rewind_by(&set, 6) == IndexCursor::from(19_998));
+    assert!(IndexCursor::end().rewind_by(&set, 1) == IndexCursor::from(200_009));
+    
+    let empty = BitSet::<Conf>::new();
+    assert!(IndexCursor::end().rewind_by(&empty, 1) == IndexCursor::start());
+}
+
+#[test]
+fn advance_to_test(){
+    type Conf = config::_64bit;
+    let set: BitSet<Conf> = (0..20_000).step_by(3).collect();
+    
+    let mut iter = set.iter();
+    iter.advance_to(5000);
+    assert_eq!(iter.next(), Some(5001));
+    iter.advance_to(9999);
+    assert_eq!(iter.next(), Some(9999));
+    // backward
+    iter.advance_to(10);
+    assert_eq!(iter.next(), Some(12));
+    iter.advance_to(100_000);
+    assert_eq!(iter.next(), None);
+    
+    let mut iter = set.block_iter();
+    iter.advance_to_block(5000);
+    assert_eq!(iter.next().unwrap().start_index, 4992);
+    
+    // no block at index
+    let set: BitSet<Conf> = [10, 5000, 100_000].into();
+    let mut iter = set.block_iter();
+    iter.advance_to_block(300);
+    assert_eq!(iter.next().unwrap().start_index, 4992);
+    let mut iter = set.iter();
+    iter.advance_to(5001);
+    assert_eq!(iter.next(), Some(100_000));
+}
+
+#[test]
+fn split_at_test(){
+    let mut rng = rand::thread_rng();
+    let mut set = HiSparseBitset::new();
+    for _ in 0..1000{
+        set.insert(rng.gen_range(0..100_000));
+    }
+    
+    for index in [0, 1, 63, 64, 1000, 4095, 4096, 50_000, 99_999, 100_000, 200_000]{
+        let (left, right) = set.split_at(index);
+        assert_equal(left.iter(), set.iter().filter(|i| *i < index));
+        assert_equal(right.iter(), set.iter().filter(|i| *i >= index));
+    }
+}
+
+#[test]
+fn bitset_macro_test(){
+    let n = 10;
+    assert_equal(bitset![].iter(), [0usize; 0]);
+    assert_equal(bitset![1, 3, 5].iter(), [1, 3, 5]);
+    assert_equal(bitset![n, n+1,].iter(), [10, 11]);
+    assert_equal(bitset![0..5].iter(), 0..5);
+    assert_equal(bitset![0..=n].iter(), 0..=10);
+    assert_equal(bitset![(n-5)..n].iter(), 5..10);
+    
+    // Default config does not depend on features.
+    let _: crate::BitSet<config::_64bit> = bitset![];
+    let _: crate::BitSet<config::_64bit> = bitset![1, 2];
+    let _: crate::BitSet<config::_64bit> = bitset![0..n];
+    
+    let set = bitset![config::_64bit; 1, 2, 3];
+    let _: &crate::BitSet<config::_64bit> = &set;
+    assert_equal(set.iter(), [1, 2, 3]);
+    assert_equal(bitset![config::_64bit<cache::NoCache>; 100..200].iter(), 100..200);
+    assert!(bitset![config::_64bit;].is_empty());
+}
+
+#[test]
+fn generation_test(){
+    let mut set = HiSparseBitset::new();
+    assert_eq!(set.generation(), 0);
+    
+    let mut generation = set.generation();
+    let mut check_changed = |set: &HiSparseBitset|{
+        assert!(set.generation() > generation);
+        generation = set.generation();
+    };
+    
+    set.insert(10);
+    check_changed(&set);
+    set.insert(20_000);
+    check_changed(&set);
+    set.remove(10);
+    check_changed(&set);
+    set |= &HiSparseBitset::from([1, 2, 3]);
+    check_changed(&set);
+    set &= &HiSparseBitset::from([1, 2]);
+    check_changed(&set);
+    set.retain(|i| i == 1);
+    check_changed(&set);
+    set.pop_first();
+    check_changed(&set);
+    set.insert(5);
+    set.clear();
+    check_changed(&set);
+    
+    // read-only
+    let _ = set.contains(5);
+    let _ = set.iter().count();
+    set.shrink_to_fit();
+    assert_eq!(set.generation(), generation);
+    
+    set.insert(5);
+    let cloned = set.clone();
+    assert_eq!(cloned.generation(), 0);
+    assert_eq!(cloned, set);
+}
+
+#[test]
+fn reduce_into_test(){
+    let mut rng = rand::thread_rng();
+    let sets: Vec<HiSparseBitset> = (0..3).map(|_|
+        (0..2000).map(|_| rng.gen_range(0..10_000)).collect()
+    ).collect();
+    
+    let mut target: crate::BitSet<Conf> = [1, 2, 50_000].into();
+    reduce_into(&mut target, Or, sets.iter());
+    assert_equal(target.iter(), reduce(Or, sets.iter()).unwrap().iter());
+    
+    reduce_into(&mut target, And, sets[..2].iter());
+    assert_equal(target.iter(), apply(And, &sets[0], &sets[1]).iter());
+    
+    reduce_into(&mut target, And, sets[..0].iter());
+    assert!(target.is_empty());
+    
+    apply_into(&mut target, Sub, &sets[0], &sets[1]);
+    assert_equal(target.iter(), apply(Sub, &sets[0], &sets[1]).iter());
+}
+
+#[test]
+fn contains_range_test(){
+    let mut set = HiSparseBitset::new();
+    assert!(set.contains_range(10..=9));
+    assert!(!set.contains_range(0..=0));
+    
+    for i in 100..=5000 {
+        set.insert(i);
+    }
+    set.insert(6000);
+    
+    assert!(set.contains_range(100..=5000));
+    assert!(set.contains_range(128..=4095));
+    assert!(set.contains_range(6000..=6000));
+    assert!(!set.contains_range(99..=5000));
+    assert!(!set.contains_range(100..=5001));
+    assert!(!set.contains_range(5000..=6000));
+    assert!(!set.contains_range(0..=usize::MAX));
+    
+    set.remove(3000);
+    assert!(!set.contains_range(100..=5000));
+    assert!(set.contains_range(3001..=5000));
+    
+    // Not TRUSTED_HIERARCHY
+    let other: HiSparseBitset = (2000..=7000).collect();
+    let sub = apply(Sub, &set, &other);
+    assert!(sub.contains_range(100..=1999));
+    assert!(!sub.contains_range(100..=2000));
+    assert!(!sub.contains_range(6000..=6000));
+    let and = apply(And, &set, &other);
+    assert!(and.contains_range(3001..=5000));
+    assert!(!and.contains_range(1000..=5000));
+}
+
+#[test]
+fn count_in_range_test(){
+    assert_eq!(HiSparseBitset::new().count_in_range(0..=100), 0);
+    
+    let (set1, set2) = random_sets(5000, 100_000);
+    
+    let ranges = [0..=0, 0..=100_000, 10..=9, 63..=64, 100..=4095, 4096..=8191, 1234..=56789, 99_000..=usize::MAX];
+    for range in ranges.clone(){
+        let expected = set1.iter().filter(|i| range.contains(i)).count();
+        assert_eq!(set1.count_in_range(range), expected);
+    }
+    
+    // Not TRUSTED_HIERARCHY
+    let and = apply(And, &set1, &set2);
+    for range in ranges{
+        let expected = and.iter().filter(|i| range.contains(i)).count();
+        assert_eq!(and.count_in_range(range), expected);
+    }
+}
+
+#[test]
+fn eq_lazy_test(){
+    let set1: HiSparseBitset = [1, 2, 3, 1000, 50_000].into();
+    let set2: HiSparseBitset = [2, 3, 4, 50_000].into();
+    let intersection: HiSparseBitset = [2, 3, 50_000].into();
+    
+    assert!(intersection == &set1 & &set2);
+    assert!(intersection == apply(And, &set1, &set2));
+    assert!(apply(And, &set1, &set2) == intersection);
+    assert!(apply(And, &set1, &set2) == reduce(And, [&set1, &set2].into_iter()).unwrap());
+    assert!(reduce(And, [&set1, &set2].into_iter()).unwrap() == &intersection);
+    assert!(set1 != apply(And, &set1, &set2));
+    assert!(apply(Or, &set1, &set2) != intersection);
+}
+
+#[test]
+fn apply_size_test(){
+    let (set1, set2) = random_sets(3000, 100_000);
+    
+    fn len(iter: impl Iterator) -> usize { iter.fold(0, |n, _| n + 1) }
+    assert_eq!(set1.intersection_size(&set2), len(apply(And, &set1, &set2).iter()));
+    assert_eq!(set1.union_size(&set2), len(apply(Or, &set1, &set2).iter()));
+    assert_eq!(set1.difference_size(&set2), len(apply(Sub, &set1, &set2).iter()));
+    assert_eq!(set1.symmetric_difference_size(&set2), len(apply(Xor, &set1, &set2).iter()));
+    
+    // lazy operand
+    let or = apply(Or, &set1, &set2);
+    assert_eq!(or.intersection_size(&set1), set1.iter().count());
+    assert_eq!(HiSparseBitset::new().union_size(&set1), set1.iter().count());
+}
+
+#[test]
+fn simple_iter_test(){
+    use crate::iter::{SimpleBlockIter, SimpleIndexIter};
+    let (set1, set2) = random_sets(1000, HiSparseBitset::max_capacity());
+    assert_equal(SimpleIndexIter::new(SimpleBlockIter::new(&set1)), set1.iter());
+    
+    let and = &set1 & &set2;
+    assert_equal(SimpleIndexIter::new(SimpleBlockIter::new(&and)), and.iter());
+    assert_equal(
+        SimpleBlockIter::new(&and).map(|block| block.start_index),
+        and.block_iter().map(|block| block.start_index)
+    );
+}
+
+#[test]
+fn fused_iter_test(){
+    use std::iter::FusedIterator;
+    fn assert_fused<I: FusedIterator>(mut iter: I){
+        while iter.next().is_some(){}
+        for _ in 0..3 {
+            assert!(iter.next().is_none());
+        }
+    }
+    
+    let set: HiSparseBitset = [1, 100, 5000].into();
+    let and = apply(And, &set, &set);
+    assert_fused(set.iter());
+    assert_fused(set.block_iter());
+    assert_fused(set.rev_iter());
+    assert_fused(set.rev_block_iter());
+    assert_fused(set.iter_ranges());
+    assert_fused(and.iter());
+    assert_fused(and.block_iter());
+    assert_fused(set.block_iter().next().unwrap().into_iter());
+}
+
+#[test]
+fn exact_iter_test(){
+    assert_eq!(HiSparseBitset::new().exact_len(), 0);
+    assert_eq!(HiSparseBitset::new().exact_iter().len(), 0);
+    
+    let (set1, set2) = random_sets(3000, 100_000);
+    
+    let indices: Vec<_> = set1.iter().collect();
+    assert_eq!(set1.exact_len(), indices.len());
+    
+    let mut iter = set1.exact_iter();
+    assert_eq!(iter.len(), indices.len());
+    iter.next();
+    assert_eq!(iter.len(), indices.len() - 1);
+    iter.nth(100);
+    assert_eq!(iter.len(), indices.len() - 102);
+    assert_equal(iter, indices.iter().copied().skip(102));
+    
+    let mut iter = set1.exact_iter();
+    assert_eq!(iter.nth(indices.len() + 10), None);
+    assert_eq!(iter.len(), 0);
+    let mut iter = set1.exact_iter();
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.len(), 0);
+    
+    // Not TRUSTED_HIERARCHY
+    let and = apply(And, &set1, &set2);
+    let and_indices: Vec<_> = and.iter().collect();
+    assert_eq!(and.exact_len(), and_indices.len());
+    let iter = and.exact_iter();
+    assert_eq!(iter.len(), and_indices.len());
+    assert_equal(iter, and_indices);
+}
+
+#[test]
+fn iter_complement_bounded_test(){
+    let (set1, set2) = random_sets(3000, 20_000);
+    
+    for max in [0, 1, 100, 4096, 15_000, 30_000]{
+        assert_equal(
+            set1.iter_complement_bounded(max),
+            (0..max).filter(|&i| !set1.contains(i))
+        );
+    }
+    assert_equal(HiSparseBitset::new().iter_complement_bounded(1000), 0..1000);
+    
+    // lazy bitset
+    let and = apply(And, &set1, &set2);
+    assert_equal(
+        and.iter_complement_bounded(25_000),
+        (0..25_000).filter(|&i| !and.contains(i))
+    );
+}
+
+#[test]
+fn iter_from_until_test(){
+    let (set1, set2) = random_sets(3000, 100_000);
+    let block_size = <Conf as Config>::DataBitBlock::size();
+    
+    for bound in [0, 1, 100, 4096, 50_000, 99_999, 200_000]{
+        assert_equal(set1.iter_from(bound), set1.iter().filter(|&i| i >= bound));
+        assert_equal(set1.iter_until(bound), set1.iter().filter(|&i| i <= bound));
+        assert_equal(
+            set1.block_iter_from(bound).map(|block| block.start_index), 
+            set1.block_iter().map(|block| block.start_index)
+                .filter(|&start_index| start_index + block_size > bound)
+        );
+        assert_equal(
+            set1.block_iter_until(bound).map(|block| block.start_index), 
+            set1.block_iter().map(|block| block.start_index)
+                .filter(|&start_index| start_index <= bound)
+        );
+    }
+    
+    // lazy bitset
+    let and = apply(And, &set1, &set2);
+    assert_equal(and.iter_from(30_000), and.iter().filter(|&i| i >= 30_000));
+    assert_equal(and.iter_until(30_000), and.iter().filter(|&i| i <= 30_000));
+    
+    // range
+    for (first, last) in [(0, 0), (100, 4096), (50_000, 99_999), (30_000, 200_000), (200_000, 300_000)]{
+        assert_equal(set1.iter_range(first..=last), set1.iter().filter(|&i| first <= i && i <= last));
+        assert_equal(set1.iter_range(first..last), set1.iter().filter(|&i| first <= i && i < last));
+        assert_equal(and.iter_range(first..last), and.iter().filter(|&i| first <= i && i < last));
+    }
+    assert_equal(set1.iter_range(..), set1.iter());
+    assert_equal(set1.iter_range(..0), [0usize; 0]);
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 5000..=4000;
+    assert_equal(set1.iter_range(empty), [0usize; 0]);
+    assert_equal(set1.iter_range((core::ops::Bound::Excluded(usize::MAX), core::ops::Bound::Unbounded)), [0usize; 0]);
+}
+
+#[test]
+fn implication_test(){
+    use crate::ops::{AndNot, Implication};
+    
+    let (set1, set2) = random_sets(3000, 20_000);
+    
+    assert_eq!(apply(AndNot, &set1, &set2), apply(Sub, &set1, &set2));
+    
+    // !(a -> b) = a - b
+    let implication = apply(Implication, &set1, &set2);
+    for i in 0..25_000{
+        assert_eq!(implication.contains(i), !set1.contains(i) || set2.contains(i));
+    }
+    assert_eq!(implication, &set1 >> &set2);
+    assert_eq!(
+        implication, 
+        ComplementBitSet::new(apply(Sub, &set1, &set2))
+    );
+    
+    let range = RangeBitSet::<Conf>::new(..25_000);
+    assert_equal(
+        apply(And, &implication, range).iter(),
+        (0..25_000).filter(|&i| !set1.contains(i) || set2.contains(i))
+    );
+}
+
+#[test]
+fn reduce_threshold_test(){
+    let mut rng = rand::thread_rng();
+    let sets: Vec<HiSparseBitset> = (0..5).map(|_|
+        (0..2000).map(|_| rng.gen_range(0..10_000)).collect()
+    ).collect();
+    
+    for k in 1..=6{
+        let result = reduce_threshold(k, sets.iter());
+        let expected = (0..10_000).filter(|&i| 
+            sets.iter().filter(|set| set.contains(i)).count() >= k
+        );
+        assert_equal(result.iter(), expected);
+    }
+    
+    assert_eq!(reduce_threshold(1, sets.iter()), reduce(Or, sets.iter()).unwrap());
+    assert_eq!(reduce_threshold(5, sets.iter()), reduce(And, sets.iter()).unwrap());
+    assert!(reduce_threshold(1, sets[..0].iter()).is_empty());
+    
+    // lazy bitsets
+    let a = apply(Or, &sets[0], &sets[1]);
+    let b = apply(Or, &sets[1], &sets[2]);
+    assert_equal(
+        reduce_threshold(2, [&a, &b]).iter(), 
+        apply(And, &a, &b).iter()
+    );
+}
+
+#[test]
+fn apply3_test(){
+    let mut rng = rand::thread_rng();
+    let sets: Vec<HiSparseBitset> = (0..3).map(|_|
+        (0..2000).map(|_| rng.gen_range(0..10_000)).collect()
+    ).collect();
+    let [s1, s2, s3] = [&sets[0], &sets[1], &sets[2]];
+    
+    let and = apply3(And, s1, s2, s3);
+    assert_equal(and.iter(), apply(And, apply(And, s1, s2), s3).iter());
+    assert_eq!(and, reduce(And, sets.iter()).unwrap());
+    
+    let or = apply3(Or, s1, s2, s3);
+    assert_equal(or.iter(), apply(Or, apply(Or, s1, s2), s3).iter());
+    
+    let xor = apply3(Xor, s1, s2, s3);
+    assert_equal(xor.iter(), apply(Xor, s1, apply(Xor, s2, s3)).iter());
+    
+    // nested
+    let or2 = apply(Or, s1, s2);
+    assert_equal(
+        apply3(And, &or2, s3, &or).iter(), 
+        apply(And, &or2, s3).iter()
+    );
+    assert!(apply3(And, s1, s2, &HiSparseBitset::new()).is_empty());
+}
+
+#[test]
+fn into_sorted_vec_test(){
+    assert!(HiSparseBitset::new().into_sorted_vec().is_empty());
+    let (set1, set2) = random_sets(3000, 100_000);
+    
+    let vec = (&set1).into_sorted_vec();
+    assert_eq!(vec.capacity(), vec.len());
+    assert_equal(vec.iter().copied(), set1.iter());
+    
+    let vec = apply(Or, &set1, &set2).into_sorted_vec();
+    assert_eq!(vec.capacity(), vec.len());
+    assert_equal(vec, apply(Or, &set1, &set2).iter());
+    
+    let vec = set1.clone().into_sorted_vec();
+    assert_equal(vec, set1.iter());
+}
+
+#[test]
+fn extend_test(){
+    let mut set = HiSparseBitset::new();
+    set.extend([1, 5, 100]);
+    set.extend(&[5, 7, 20_000]);
+    set.extend(vec![3usize].iter());
+    assert_equal(set.iter(), [1, 3, 5, 7, 100, 20_000]);
+    
+    let other: HiSparseBitset = [2, 100, 50_000].into();
+    set.extend(other.block_iter());
+    assert_equal(set.iter(), [1, 2, 3, 5, 7, 100, 20_000, 50_000]);
+    
+    let mut set2 = HiSparseBitset::new();
+    set2.extend(apply(Sub, &set, &other).block_iter());
+    assert_eq!(set2, apply(Sub, &set, &other));
+}
+
+#[test]
+fn update_methods_test(){
+    let (set1, set2) = random_sets(3000, 100_000);
+    
+    let mut set = set1.clone();
+    set.intersection_update(&set2);
+    assert_eq!(set, apply(And, &set1, &set2));
+    
+    let mut set = set1.clone();
+    set.union_update(&set2);
+    assert_eq!(set, apply(Or, &set1, &set2));
+    
+    let mut set = set1.clone();
+    set.difference_update(&set2);
+    assert_eq!(set, apply(Sub, &set1, &set2));
+    
+    let mut set = set1.clone();
+    set.symmetric_difference_update(&set2);
+    assert_eq!(set, apply(Xor, &set1, &set2));
+    
+    // lazy operand
+    let mut set = set1.clone();
+    set.intersection_update(apply(Or, &set2, &set1));
+    assert_eq!(set, set1);
+    set.difference_update(&set1);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn from_sorted_slice_test(){
+    let mut rng = rand::thread_rng();
+    let mut indices: Vec<usize> = (0..3000).map(|_| rng.gen_range(0..100_000)).collect();
+    indices.sort();
+    
+    let set = HiSparseBitset::from_sorted_slice(&indices);
+    assert_eq!(set, indices.iter().copied().collect::<HiSparseBitset>());
+    
+    // duplicates, block boundaries
+    let set = HiSparseBitset::from_sorted_slice(&[0, 0, 63, 64, 64, 4095, 4096, 100_000]);
+    assert_equal(set.iter(), [0, 63, 64, 4095, 4096, 100_000]);
+    
+    assert!(HiSparseBitset::from_sorted_slice(&[]).is_empty());
+}
+
+#[test]
+fn union_with_sorted_iter_test(){
+    let mut rng = rand::thread_rng();
+    let mut set: HiSparseBitset = (0..100_000).step_by(7).collect();
+    let mut indices: Vec<usize> = (0..3000).map(|_| rng.gen_range(0..100_000)).collect();
+    indices.sort();
+    
+    let mut expected = set.clone();
+    expected.extend(indices.iter().copied());
+    set.union_with_sorted_iter(indices.iter().copied());
+    assert_eq!(set, expected);
+    
+    // duplicates, block boundaries
+    let mut set: HiSparseBitset = [1, 4096].into();
+    set.union_with_sorted_iter([0, 0, 63, 64, 64, 4095, 100_000]);
+    assert_equal(set.iter(), [0, 1, 63, 64, 4095, 4096, 100_000]);
+    
+    set.union_with_sorted_iter(core::iter::empty());
+    assert_equal(set.iter(), [0, 1, 63, 64, 4095, 4096, 100_000]);
+}
+
+#[test]
+fn data_block_hash_ord_test(){
+    use std::cmp::Ordering;
+    
+    let set1: HiSparseBitset = [1, 2, 5000, 10_000].into();
+    let set2: HiSparseBitset = [1, 2, 5001, 20_000].into();
+    
+    let blocks: HashSet<_> = set1.block_iter().chain(set1.block_iter()).collect();
+    assert_eq!(blocks.len(), set1.block_iter().count());
+    let blocks: HashSet<_> = set1.block_iter().chain(set2.block_iter()).collect();
+    assert_eq!(blocks.len(), 5);
+    
+    let blocks1: Vec<_> = set1.block_iter().collect();
+    let blocks2: Vec<_> = set2.block_iter().collect();
+    assert!(blocks1.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(blocks1[0].cmp(&blocks2[0]), Ordering::Equal);
+    // same start_index, 5000 < 5001
+    assert_eq!(blocks1[1].start_index, blocks2[1].start_index);
+    assert!(blocks1[1] < blocks2[1]);
+    assert!(blocks1[2] < blocks2[2]);
+}
+
+#[test]
+fn jaccard_hamming_test(){
+    let empty = HiSparseBitset::new();
+    assert_eq!(empty.jaccard_similarity(&empty), 1.0);
+    assert_eq!(empty.hamming_distance(&empty), 0);
+    
+    let (set1, set2) = random_sets(3000, 20_000);
+    
+    let intersection = apply(And, &set1, &set2).iter().count();
+    let union = apply(Or, &set1, &set2).iter().count();
+    assert_eq!(set1.jaccard_similarity(&set2), intersection as f64 / union as f64);
+    assert_eq!(set1.jaccard_similarity(&set1), 1.0);
+    assert_eq!(set1.jaccard_similarity(&empty), 0.0);
+    
+    assert_eq!(set1.hamming_distance(&set2), union - intersection);
+    assert_eq!(set1.hamming_distance(&set1), 0);
+    assert_eq!(set1.hamming_distance(&empty), set1.iter().count());
+    
+    // lazy
+    let or = apply(Or, &set1, &set2);
+    assert_eq!(or.jaccard_similarity(&set1), set1.iter().count() as f64 / union as f64);
+}
+
+#[test]
+fn is_contiguous_test(){
+    let mut set = HiSparseBitset::new();
+    assert!(set.is_contiguous());
+    assert_eq!(set.to_range(), None);
+    
+    set.insert(5);
+    assert_eq!(set.to_range(), Some(5..=5));
+    
+    for i in 6..10_000{
+        set.insert(i);
+    }
+    assert!(set.is_contiguous());
+    assert_eq!(set.to_range(), Some(5..=9_999));
+    
+    set.insert(20_000);
+    assert!(!set.is_contiguous());
+    assert_eq!(set.to_range(), None);
+    
+    set.remove(20_000);
+    set.remove(700);
+    assert!(!set.is_contiguous());
+    
+    // Not TRUSTED_HIERARCHY
+    let other: HiSparseBitset = (1000..20_000).collect();
+    let and = &set & &other;
+    assert_eq!(and.to_range(), Some(1000..=9_999));
+    let sub = &set - &other;
+    assert_eq!(sub.to_range(), None);
+}
+
+#[test]
+fn first_unset_test(){
+    let mut set: HiSparseBitset = (0..10_000).collect();
+    assert_eq!(set.first_unset(..10_000), None);
+    assert_eq!(set.first_unset(..10_001), Some(10_000));
+    assert_eq!(set.first_unset(..0), None);
+    assert_eq!(HiSparseBitset::new().first_unset(..5), Some(0));
+    
+    set.remove(7000);
+    set.remove(8000);
+    assert_eq!(set.first_unset(..7000), None);
+    assert_eq!(set.first_unset(..20_000), Some(7000));
+    set.insert(7000);
+    assert_eq!(set.first_unset(..20_000), Some(8000));
+    
+    // lazy bitset
+    let other: HiSparseBitset = (0..5000).chain(5001..9000).collect();
+    let and = &set & &other;
+    assert_eq!(and.first_unset(..20_000), Some(5000));
+    assert_eq!(and.first_unset(..20_000), and.iter_complement_bounded(20_000).next());
+    
+    // hint
+    assert_eq!(set.first_unset(5000..), Some(8000));
+    assert_eq!(set.first_unset(8001..), Some(10_000));
+    assert_eq!(set.first_unset(8000..=8000), Some(8000));
+    assert_eq!(set.first_unset(100..8000), None);
+    assert_eq!(and.first_unset(5001..), Some(8000));
+    assert_eq!(HiSparseBitset::new().first_unset(12345..), Some(12345));
+    
+    let max = HiSparseBitset::max_capacity();
+    let full: HiSparseBitset = (max - 100..max).collect();
+    assert_eq!(full.first_unset(max - 100..), None);
+    assert_eq!(full.first_unset(max - 200..), Some(max - 200));
+}
+
+#[test]
+fn debug_display_test(){
+    let mut set = HiSparseBitset::new();
+    assert_eq!(format!("{:?}", set), "{}");
+    assert_eq!(format!("{}", set), "");
+    
+    for i in (1..=5).chain([7]).chain(100..=102).chain(4000..=9000){
+        set.insert(i);
+    }
+    assert_eq!(format!("{:?}", set), "{1..=5, 7, 100..=102, 4000..=9000}");
+    assert_eq!(format!("{}", set), "1..=5, 7, 100..=102, 4000..=9000");
+    
+    let other: HiSparseBitset = (0..=4).collect();
+    assert_eq!(format!("{:?}", &set & &other), "{1..=4}");
+    assert_eq!(format!("{}", &set - &other), "5, 7, 100..=102, 4000..=9000");
+}
+
+#[test]
+fn approximate_memory_usage_test(){
+    use crate::SmallBitSet;
+    
+    let set: crate::BitSet<config::_64bit> = (0..100_000).step_by(3).collect();
+    assert_eq!(set.approximate_memory_usage(), set.memory_usage().total());
+    
+    // Inflated level1 block heap is not counted.
+    let small_set: SmallBitSet<config::_64bit> = (0..64*64).collect();
+    let usage = small_set.memory_usage();
+    assert!(small_set.approximate_memory_usage() < usage.total());
+    assert!(small_set.approximate_memory_usage() >= usage.level0_bytes + usage.data_allocated_capacity_bytes);
+}
+
+#[test]
+fn chunks_test(){
+    let (set1, set2) = random_sets(3000, 20_000);
+    let v: Vec<usize> = set1.iter().collect();
+    
+    for chunk_size in [1, 7, 64, 100, 1000, 10_000]{
+        let chunks: Vec<_> = set1.chunks(chunk_size).collect();
+        assert_eq!(chunks.len(), v.len().div_ceil(chunk_size));
+        for (chunk, expected) in zip(&chunks, v.chunks(chunk_size)){
+            assert_equal(chunk.iter(), expected.iter().copied());
+        }
+    }
+    assert_eq!(HiSparseBitset::new().chunks(10).next(), None);
+    
+    // lazy bitset
+    let and = apply(And, &set1, &set2);
+    let v: Vec<usize> = and.iter().collect();
+    let chunks: Vec<_> = and.chunks(50).collect();
+    assert_eq!(chunks.len(), v.len().div_ceil(50));
+    assert_equal(chunks.iter().flat_map(|chunk| chunk.iter()), v);
+}
+
+#[test]
+fn complement_op_test(){
+    use crate::ops::complement;
+    
+    let filter: HiSparseBitset = (0..25_000).step_by(3).collect();
+    let (set1, set2) = random_sets(3000, 20_000);
+    
+    // NAND
+    let nand = apply(And, &filter, complement(apply(And, &set1, &set2)));
+    assert_equal(
+        nand.iter(),
+        filter.iter().filter(|&i| !(set1.contains(i) && set2.contains(i)))
+    );
+    assert_eq!(nand, apply(And, &filter, !(&set1 & &set2)));
+    
+    assert_eq!(apply(And, &set1, !&set2), &set1 - &set2);
+    assert_eq!(!&set1, ComplementBitSet::new(&set1));
+}
+
+#[test]
+fn retain_range_test(){
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let mut set = HiSparseBitset::new();
+        for _ in 0..2000{
+            set.insert(rng.gen_range(0..50_000));
+        }
+        let first = rng.gen_range(0..50_000);
+        let last  = rng.gen_range(first..50_000);
+        
+        let mut retained = set.clone();
+        retained.retain_range(first..=last);
+        assert_equal(retained.iter(), set.iter().filter(|i| (first..=last).contains(i)));
+        
+        let mut retained = set.clone();
+        retained.retain_range(..first);
+        assert_equal(retained.iter(), set.iter().filter(|&i| i < first));
+    }
+    
+    let mut set: HiSparseBitset = (0..10_000).collect();
+    set.retain_range(20_000..);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn xor_assign_range_test(){
+    let block_size = <Conf as Config>::DataBitBlock::size();
+    let mut set: HiSparseBitset = [1, 10, block_size + 3, block_size * 5, block_size * 9].into();
+    let original: HashSet<usize> = set.iter().collect();
+    let range = 5..=block_size * 5;
+    let flipped: HashSet<usize> = range.clone().collect();
+    let mut expected: Vec<usize> = original.symmetric_difference(&flipped).copied().collect();
+    expected.sort();
+    
+    set.xor_assign_range(range.clone());
+    assert_equal(set.iter(), expected.iter().copied());
+    
+    // Flip back
+    set.xor_assign_range(range);
+    assert_equal(set.iter(), [1, 10, block_size + 3, block_size * 5, block_size * 9]);
+    
+    // Whole block
+    set.xor_assign_range(block_size * 5..block_size * 6);
+    assert_equal(set.iter(), 
+        [1, 10, block_size + 3].into_iter()
+        .chain(block_size * 5 + 1..block_size * 6)
+        .chain([block_size * 9])
+    );
+    set.xor_assign_range(block_size * 5..block_size * 6);
+    // Flipped to empty - removed
+    set.xor_assign_range(block_size * 9..=block_size * 9);
+    assert_equal(set.iter(), [1, 10, block_size + 3, block_size * 5]);
+    assert_eq!(set.block_iter().count(), 3);
+    
+    set.xor_assign_range(HiSparseBitset::max_capacity()..);
+    assert_equal(set.iter(), [1, 10, block_size + 3, block_size * 5]);
+}
+
+#[test]
+fn block_iter_count_test(){
+    let mut rng = rand::thread_rng();
+    let mut sets: Vec<HiSparseBitset> = Vec::new();
+    for _ in 0..3 {
+        let mut set = HiSparseBitset::new();
+        for _ in 0..2000{
+            set.insert(rng.gen_range(0..60_000));
+        }
+        sets.push(set);
+    }
+    
+    fn test<S: BitSetInterface>(set: S){
+        assert_eq!(set.block_iter().count(), set.block_iter().fold(0, |n, _| n + 1));
+        assert_eq!(set.iter().count(), set.iter().fold(0, |n, _| n + 1));
+        
+        // partially consumed
+        let mut iter = set.block_iter();
+        iter.nth(10);
+        let len = iter.clone().fold(0, |n, _| n + 1);
+        assert_eq!(iter.count(), len);
+    }
+    test(&sets[0]);
+    test(apply(And, &sets[0], &sets[1]));
+    test(apply(Sub, &sets[0], &sets[1]));
+    test(reduce(Or, sets.iter()).unwrap());
+    test(reduce_w_cache(And, sets.iter(), FixedCache::<3>).unwrap());
+    test(ComplementBitSet::new(&sets[0]));
+    test(OffsetBitSet::new(&sets[0], 1000));
+}
+
+#[test]
+fn nand_nor_test(){
+    use crate::ops::{Nand, Nor};
+    
+    let (set1, set2) = random_sets(3000, 20_000);
+    
+    let nand = apply(Nand, &set1, &set2);
+    let nor  = apply(Nor, &set1, &set2);
+    for i in 0..25_000{
+        assert_eq!(nand.contains(i), !(set1.contains(i) && set2.contains(i)));
+        assert_eq!(nor.contains(i), !(set1.contains(i) || set2.contains(i)));
+    }
+    assert_eq!(nand, !(&set1 & &set2));
+    assert_eq!(nor, !(&set1 | &set2));
+    
+    let range = RangeBitSet::<Conf>::new(..25_000);
+    assert_equal(
+        apply(And, &nand, range).iter(),
+        (0..25_000).filter(|&i| !(set1.contains(i) && set2.contains(i)))
+    );
+    assert_equal(
+        apply(And, &nor, range).iter(),
+        (0..25_000).filter(|&i| !(set1.contains(i) || set2.contains(i)))
+    );
+}
+
+#[test]
+fn smart_pointer_level_masks_test(){
+    use std::rc::Rc;
+    use std::sync::Arc;
+    
+    let set1: HiSparseBitset = (0..1000).step_by(3).collect();
+    let set2: HiSparseBitset = (0..1000).step_by(5).collect();
+    let and = apply(And, &set1, &set2);
+    
+    let boxed = Box::new(set1.clone());
+    assert!(internals::is_eq(&boxed, &set1));
+    assert_eq!(internals::exact_len(&boxed), set1.iter().count());
+    assert_eq!(internals::first(Rc::new(set2.clone())), Some(0));
+    assert!(internals::contains(Arc::new(set2.clone()), 995));
+    assert!(internals::is_eq(Box::new(and.clone()), &and));
+    assert!(internals::is_eq(Arc::new(and.clone()), &and));
+    
+    let boxed_sets = vec![Box::new(set1.clone()), Box::new(set2.clone())];
+    assert!(internals::is_eq(reduce(And, boxed_sets.iter().map(|b| b.as_ref())).unwrap(), &and));
+}
+
+#[test]
+fn swap_move_into_test(){
+    let mut set1: HiSparseBitset = (0..1000).step_by(3).collect();
+    let mut set2: HiSparseBitset = (500..2000).step_by(5).collect();
+    let expected1 = set1.clone();
+    let expected2 = set2.clone();
+    
+    set1.swap(&mut set2);
+    assert_eq!(set1, expected2);
+    assert_eq!(set2, expected1);
+    
+    set1.move_into(&mut set2);
+    assert!(set1.is_empty());
+    assert_eq!(set2, &expected1 | &expected2);
+    
+    // to empty
+    let mut empty = HiSparseBitset::new();
+    set2.move_into(&mut empty);
+    assert!(set2.is_empty());
+    assert_eq!(empty, &expected1 | &expected2);
+    
+    set1.move_into(&mut empty);
+    assert_eq!(empty, &expected1 | &expected2);
+}
+
+#[test]
+fn cursor_midpoint_test(){
+    type Conf = config::_64bit;
+    type Cursor = BlockCursor<Conf>;
+    
+    let start = Cursor::start();
+    let end = Cursor::end();
+    let mid = Cursor::midpoint(start, end);
+    assert!(start.is_before(&mid) && mid.is_before(&end));
+    assert!(mid == Cursor::midpoint(end, start));
+    assert!(Cursor::midpoint(mid, mid) == mid);
+    assert!(Cursor::midpoint(Cursor::from(0), Cursor::from(64*64*2)) == Cursor::from(64*64));
+    assert!(Cursor::midpoint(Cursor::from(0), Cursor::from(64*10)) == Cursor::from(64*5));
+    
+    let set: BitSet<Conf> = (0..100_000).step_by(3).collect();
+    let count = |a, b| Cursor::element_count_between(&set, a, b);
+    assert_eq!(count(start, end), set.iter().count());
+    assert_eq!(count(start, mid) + count(mid, end), set.iter().count());
+    assert_eq!(count(end, start), 0);
+    assert_eq!(
+        count(Cursor::from(640), Cursor::from(6400)), 
+        set.iter().filter(|&i| (640..6400).contains(&i)).count()
+    );
+    
+    // lazy
+    let other: BitSet<Conf> = (0..100_000).step_by(2).collect();
+    let and = apply(And, &set, &other);
+    assert_eq!(
+        Cursor::element_count_between(&and, start, mid) + Cursor::element_count_between(&and, mid, end),
+        and.iter().count()
+    );
+}
+
+#[test]
+fn check_op_test(){
+    use crate::ops::{check, DisjointCheck, SubsetCheck, SupersetCheck};
+    
+    let set1: HiSparseBitset = (0..10_000).step_by(6).collect();
+    let set2: HiSparseBitset = (0..10_000).step_by(3).collect();
+    let set3: HiSparseBitset = (1..10_000).step_by(3).collect();
+    
+    assert!(check(SubsetCheck, &set1, &set2));
+    assert!(!check(SubsetCheck, &set2, &set1));
+    assert!(check(SupersetCheck, &set2, &set1));
+    assert!(!check(SupersetCheck, &set1, &set2));
+    assert!(check(DisjointCheck, &set1, &set3));
+    assert!(!check(DisjointCheck, &set1, &set2));
+    
+    // lazy
+    let or = &set1 | &set3;
+    assert!(check(SubsetCheck, &set1, &or));
+    assert!(check(SupersetCheck, &or, &set3));
+    assert!(check(DisjointCheck, &set3, &set1 & &set2));
+    
+    for (a, b) in [(&set1, &set2), (&set2, &set3), (&set3, &set1)]{
+        assert_eq!(check(SubsetCheck, a, b), a.is_subset(b));
+        assert_eq!(check(SupersetCheck, a, b), a.is_superset(b));
+        assert_eq!(check(DisjointCheck, a, b), a.is_disjoint(b));
+    }
+}
+
+#[test]
+fn apply_batch_test(){
+    let mut rng = rand::thread_rng();
+    let mut sets: Vec<HiSparseBitset> = Vec::new();
+    for _ in 0..4 {
+        let mut set = HiSparseBitset::new();
+        for _ in 0..3000{
+            set.insert(rng.gen_range(0..20_000));
+        }
+        sets.push(set);
+    }
+    
+    fn test<Op: BitSetOp>(op: Op, sets: &[HiSparseBitset]){
+        let batch = HiSparseBitset::apply_batch(op, sets);
+        assert_equal(batch.iter(), reduce(op, sets.iter()).unwrap().iter());
+    }
+    test(And, &sets);
+    test(Or, &sets);
+    test(Xor, &sets);
+    test(Sub, &sets);
+    test(And, &sets[..1]);
+    assert!(HiSparseBitset::apply_batch(Or, &sets[..0]).is_empty());
+    
+    // "Full" hierarchy ops. Not associative - two sets only.
+    fn test_full<Op: BitSetOp>(op: Op, sets: &[HiSparseBitset]){
+        let batch = HiSparseBitset::apply_batch(op, &sets[..2]);
+        let max = HiSparseBitset::max_capacity();
+        assert_equal(
+            batch.block_iter(),
+            apply(op, &sets[0], &sets[1]).block_iter()
+                .filter(|block| block.start_index < max && !block.is_empty())
+        );
+    }
+    test_full(crate::ops::Nand, &sets);
+    test_full(crate::ops::Nor, &sets);
+    test_full(crate::ops::Implication, &sets);
+    
+    // More sets, than FixedCache can hold.
+    let sets: Vec<HiSparseBitset> = (0..40).map(|i|
+        (0..2000).map(|_| rng.gen_range(0..20_000) / (i % 3 + 1)).collect()
+    ).collect();
+    let batch = HiSparseBitset::apply_batch(Or, &sets);
+    assert_equal(batch.iter(), reduce_w_cache(Or, sets.iter(), DynamicCache).unwrap().iter());
+    let batch = HiSparseBitset::apply_batch(Xor, &sets);
+    assert_equal(batch.iter(), reduce_w_cache(Xor, sets.iter(), DynamicCache).unwrap().iter());
+}